@@ -117,7 +117,9 @@ impl SettingsApp {
             #[cfg(feature = "page-power")]
             PageCommands::Power => self.pages.page_id::<power::Page>(),
             #[cfg(feature = "page-region")]
-            PageCommands::RegionLanguage => self.pages.page_id::<time::region::Page>(),
+            PageCommands::RegionLanguage { command: _ } => {
+                self.pages.page_id::<time::region::Page>()
+            }
             #[cfg(feature = "page-sound")]
             PageCommands::Sound => self.pages.page_id::<sound::Page>(),
             PageCommands::StartupApps => self.pages.page_id::<applications::startup_apps::Page>(),
@@ -1210,11 +1212,15 @@ impl SettingsApp {
                 column::with_capacity(sub_pages.len()),
                 |page_list, entity| {
                     let sub_page = &self.pages.info[entity];
+                    let badge = self.pages.page[entity]
+                        .badge()
+                        .map(|count| count.to_string())
+                        .unwrap_or_default();
 
                     page_list.push(crate::widget::page_list_item(
                         sub_page.title.as_str(),
                         sub_page.description.as_str(),
-                        "",
+                        badge,
                         &sub_page.icon_name,
                         entity,
                     ))
@@ -60,6 +60,22 @@ pub enum AppearanceCommands {
     },
 }
 
+#[cfg(feature = "page-region")]
+#[derive(Subcommand, Debug, Serialize, Deserialize, Clone)]
+pub enum RegionCommands {
+    /// List the locales which can be selected
+    ListLocales,
+    /// Print the current system locale variables
+    GetLocale,
+    /// Set the system language and regional formats
+    SetLocale {
+        /// Locale of the system language, such as `de_DE.UTF-8`
+        lang: String,
+        /// Locale of the regional formats, such as `de_AT.UTF-8`
+        region: String,
+    },
+}
+
 #[derive(Subcommand, Debug, Serialize, Deserialize, Clone)]
 pub enum PageCommands {
     /// Accessibility settings page
@@ -124,7 +140,10 @@ pub enum PageCommands {
     Power,
     /// Region & Language settings page
     #[cfg(feature = "page-region")]
-    RegionLanguage,
+    RegionLanguage {
+        #[command(subcommand)]
+        command: Option<RegionCommands>,
+    },
     /// Sound settings page
     #[cfg(feature = "page-sound")]
     Sound,
@@ -213,6 +232,17 @@ pub fn main() -> color_eyre::Result<()> {
         };
     }
 
+    #[cfg(feature = "page-region")]
+    if let Some(PageCommands::RegionLanguage { command: Some(cmd) }) = &args.sub_command {
+        return match cmd {
+            RegionCommands::ListLocales => pages::time::region::commands::list_locales(),
+            RegionCommands::GetLocale => pages::time::region::commands::get_locale(),
+            RegionCommands::SetLocale { lang, region } => {
+                pages::time::region::commands::set_locale(lang, region)
+            }
+        };
+    }
+
     let settings = cosmic::app::Settings::default()
         .size_limits(Limits::NONE.min_width(360.0).min_height(300.0));
 
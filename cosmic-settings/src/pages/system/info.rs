@@ -166,13 +166,13 @@ impl Info {
             total_capacity += disk.total_space();
         }
 
-        info.disk_capacity = format_size(total_capacity);
+        info.disk_capacity = crate::utils::format_size(total_capacity);
 
         if let Some(name) = sysinfo::System::host_name() {
             info.device_name = name;
         }
 
-        info.memory = format_size(sys.total_memory());
+        info.memory = crate::utils::format_size(sys.total_memory());
 
         if let Ok(mut session) = std::env::var("XDG_SESSION_TYPE") {
             if let Some(first) = session.get_mut(0..1) {
@@ -298,22 +298,6 @@ fn read_to_string<P: AsRef<OsStr>>(path: P) -> Option<String> {
     std::fs::read_to_string(path.as_ref()).ok()
 }
 
-fn format_size(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
-    const FACTOR: f64 = 1024.0;
-
-    if bytes == 0 {
-        return String::from("0 B");
-    }
-
-    let bytes_f64 = bytes as f64;
-    let exp = (bytes_f64.ln() / FACTOR.ln()).floor() as usize;
-    let exp = exp.min(UNITS.len() - 1);
-    let value = bytes_f64 / FACTOR.powi(exp as i32);
-
-    format!("{:.2} {}", value, UNITS[exp])
-}
-
 /// Get all GPUs from lspci with their vendor ID, device ID, and name.
 fn get_all_lspci_gpus() -> Vec<(u32, u32, String)> {
     let mut gpus = Vec::new();
@@ -1,66 +1,242 @@
 // Copyright 2023 System76 <info@system76.com>
 // SPDX-License-Identifier: GPL-3.0-only
 
+use super::shared_config::{
+    self, FIRST_DAY_OF_WEEK, MILITARY_TIME, TIME_APPLET_CONFIG, TIME_APPLET_CONFIG_VERSION,
+    TIME_FORMAT_OVERRIDE, TimeFormatOverride,
+};
 use crate::widget::selection_context_item;
 use cosmic::app::ContextDrawer;
 use cosmic::cosmic_config::{self, ConfigGet, ConfigSet};
 use cosmic::iced::core::text::Wrapping;
+use cosmic::iced::{Alignment, Length, Subscription};
 use cosmic::widget::{self, dropdown, settings};
 use cosmic::{Apply, Element, Task, surface};
 use cosmic_settings_page::{self as page, Section, section};
-use icu::calendar::types::Weekday;
-use icu::calendar::{Gregorian, week};
+use icu::calendar::Gregorian;
 use icu::datetime::input::{Date, DateTime, Time};
 use icu::datetime::options::TimePrecision;
-use icu::datetime::{DateTimeFormatter, DateTimeFormatterPreferences, fieldsets};
+use icu::datetime::{
+    DateTimeFormatter, DateTimeFormatterPreferences, NoCalendarFormatter, fieldsets,
+};
 use icu::locale::Locale;
 use icu::locale::preferences::extensions::unicode::keywords::HourCycle;
+use icu::time::zone::IanaParser;
 use slotmap::{Key, SlotMap};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::net::ToSocketAddrs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 pub use timedate_zbus::TimeDateProxy;
 use tracing::error;
 
+/// The compact text form of the tzdata database, which systemd-timedated lists timezones from.
+const TZDATA_ZI: &str = "/usr/share/zoneinfo/tzdata.zi";
+
+/// The tzdata tables of the timezones of each country, preferring the one which lists every
+/// timezone of a country rather than only those differing since 1970.
+const ZONE_TABS: [&str; 2] = [
+    "/usr/share/zoneinfo/zone.tab",
+    "/usr/share/zoneinfo/zone1970.tab",
+];
+
+/// The file name of the calendar reminder for the next daylight saving time transition, which
+/// is written to the user's runtime directory.
+const DST_REMINDER_FILE: &str = "dst_reminder.ics";
+
+/// The predominant locale of the country in which a timezone is located.
+#[cfg(feature = "page-region")]
+const TIMEZONE_LOCALES: &[(&str, &str)] = &[
+    ("America/Argentina/Buenos_Aires", "es_AR.UTF-8"),
+    ("America/Bogota", "es_CO.UTF-8"),
+    ("America/Mexico_City", "es_MX.UTF-8"),
+    ("America/Sao_Paulo", "pt_BR.UTF-8"),
+    ("Asia/Bangkok", "th_TH.UTF-8"),
+    ("Asia/Ho_Chi_Minh", "vi_VN.UTF-8"),
+    ("Asia/Jakarta", "id_ID.UTF-8"),
+    ("Asia/Jerusalem", "he_IL.UTF-8"),
+    ("Asia/Seoul", "ko_KR.UTF-8"),
+    ("Asia/Shanghai", "zh_CN.UTF-8"),
+    ("Asia/Taipei", "zh_TW.UTF-8"),
+    ("Asia/Tehran", "fa_IR.UTF-8"),
+    ("Asia/Tokyo", "ja_JP.UTF-8"),
+    ("Europe/Amsterdam", "nl_NL.UTF-8"),
+    ("Europe/Athens", "el_GR.UTF-8"),
+    ("Europe/Berlin", "de_DE.UTF-8"),
+    ("Europe/Bucharest", "ro_RO.UTF-8"),
+    ("Europe/Budapest", "hu_HU.UTF-8"),
+    ("Europe/Copenhagen", "da_DK.UTF-8"),
+    ("Europe/Helsinki", "fi_FI.UTF-8"),
+    ("Europe/Istanbul", "tr_TR.UTF-8"),
+    ("Europe/Kyiv", "uk_UA.UTF-8"),
+    ("Europe/Lisbon", "pt_PT.UTF-8"),
+    ("Europe/Madrid", "es_ES.UTF-8"),
+    ("Europe/Oslo", "nb_NO.UTF-8"),
+    ("Europe/Paris", "fr_FR.UTF-8"),
+    ("Europe/Prague", "cs_CZ.UTF-8"),
+    ("Europe/Rome", "it_IT.UTF-8"),
+    ("Europe/Stockholm", "sv_SE.UTF-8"),
+    ("Europe/Vienna", "de_AT.UTF-8"),
+    ("Europe/Warsaw", "pl_PL.UTF-8"),
+    ("Europe/Zurich", "de_CH.UTF-8"),
+];
+
+/// Timezones shown in the world clock, in the time applet's config.
+const PINNED_TIMEZONES: &str = "pinned_timezones";
+
+/// A second locale in which the current time is shown, in the time applet's config.
+const SECONDARY_TIME_LOCALE: &str = "secondary_time_locale";
+
+const TIMESYNCD_SERVICE: &str = "systemd-timesyncd";
+
+const TIMESYNCD_DROP_IN: &str = "/etc/systemd/timesyncd.conf.d/cosmic-settings.conf";
+
 crate::cache_dynamic_lazy! {
     static WEEKDAYS: [String; 4] = [fl!("time-format", "friday"), fl!("time-format", "saturday"), fl!("time-format", "sunday"), fl!("time-format", "monday")];
+    static SECONDARY_LOCALE_NONE: String = fl!("time-date", "secondary-locale-none");
+}
+
+#[derive(Clone, Debug)]
+pub enum IcalError {
+    Io(String),
+    MissingTimezone,
+    /// The TZID is not an IANA timezone name.
+    UnknownTimezone(String),
+}
+
+impl fmt::Display for IcalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(why) => f.write_str(why),
+            Self::MissingTimezone => f.write_str(&fl!("time-zone", "ical-missing")),
+            Self::UnknownTimezone(tzid) => {
+                f.write_str(&fl!("time-zone", "ical-unknown", timezone = tzid.as_str()))
+            }
+        }
+    }
+}
+
+impl std::error::Error for IcalError {}
+
+/// Where the list of selectable timezones comes from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimezoneSource {
+    /// Listed by systemd-timedated, without a local tzdata database.
+    Systemd,
+    /// Read from the tzdata database in `/usr/share/zoneinfo`.
+    Tzdata,
+    /// Listed by systemd-timedated from the local tzdata database.
+    Both,
+    #[default]
+    Unknown,
 }
 
 #[derive(Debug, Clone)]
 pub struct Info {
+    pub local_rtc: bool,
     pub ntp_enabled: bool,
+    pub ntp_synchronized: bool,
+    /// The maximum estimated error of the clock, if synchronized by systemd-timesyncd.
+    pub ntp_accuracy: Option<Duration>,
     pub timezone_id: Option<usize>,
     pub timezone_list: Vec<String>,
+    pub timezone_source: TimezoneSource,
+    /// The release of the tzdata database, such as `2024a`.
+    pub tzdata_version: Option<String>,
+    /// The timezones of each country, by ISO 3166-1 alpha-2 code.
+    pub country_timezones: BTreeMap<String, Vec<String>>,
 }
 
 pub struct Page {
     entity: page::Entity,
     cosmic_applet_config: cosmic_config::Config,
+    /// Settings which only this page uses, rather than the time applet.
+    settings_config: Option<cosmic_config::Config>,
     first_day_of_week: usize,
-    military_time: bool,
+    time_format_override: TimeFormatOverride,
+    /// Whether the locale uses a 24-hour clock, looked up when the page is refreshed.
+    locale_default_24h: bool,
     show_seconds: bool,
+    /// Whether the hardware clock keeps local time rather than UTC.
+    local_rtc: bool,
     ntp_enabled: bool,
+    ntp_synchronized: bool,
+    ntp_accuracy: Option<Duration>,
     show_date_in_top_panel: bool,
     timezone_context: bool,
     local_time: Option<DateTime<Gregorian>>,
     timezone: Option<usize>,
     timezone_list: Vec<String>,
+    /// Timezone IDs paired with their names in the user's language.
+    timezone_labels: Vec<String>,
     timezone_search: String,
+    /// The current UTC offset of the selected timezone.
+    timezone_offset: String,
+    /// The current abbreviation of the selected timezone, such as `CET`.
+    timezone_abbreviation: String,
     formatted_date: String,
+    ntp_server_input: String,
+    ntp_server_error: Option<NtpHostnameValidation>,
+    /// Locale suggested from the most recently selected timezone, with its language's name.
+    suggested_locale: Option<(&'static str, String)>,
+    /// Timezones whose current time is shown alongside the local time.
+    pinned_timezones: Vec<String>,
+    /// The instant displayed by the world clock, so that all of its times agree.
+    world_clock_now: jiff::Timestamp,
+    /// A time of day in the selected timezone to convert to UTC.
+    conversion_input: String,
+    conversion_result: Option<String>,
+    /// The timezone found in an imported calendar file, or why none could be used.
+    ical_timezone: Option<Result<usize, IcalError>>,
+    timezone_source: TimezoneSource,
+    tzdata_version: Option<String>,
+    /// A second locale in which the current time is shown, such as `ja_JP.UTF-8`.
+    secondary_time_locale: Option<String>,
+    /// The region name of the secondary locale, and whether it uses a 24-hour clock.
+    secondary_locale_name: String,
+    secondary_locale_24h: bool,
+    secondary_formatted_date: String,
+    secondary_locale_context: bool,
+    /// Installed locales and their region names, for choosing the secondary locale.
+    secondary_locales: Vec<(String, String)>,
+    secondary_locale_search: String,
+    /// The timezones of each country, by ISO 3166-1 alpha-2 code.
+    country_timezones: BTreeMap<String, Vec<String>>,
+    /// Whether the other timezones of the selected timezone's country are listed.
+    country_timezones_expanded: bool,
 }
 
 impl Default for Page {
     fn default() -> Self {
         let cosmic_applet_config =
-            cosmic_config::Config::new("com.system76.CosmicAppletTime", 1).unwrap();
+            cosmic_config::Config::new(TIME_APPLET_CONFIG, TIME_APPLET_CONFIG_VERSION).unwrap();
+
+        let locale_default_24h = get_locale_default_24h();
 
-        let military_time = cosmic_applet_config
-            .get("military_time")
+        let time_format_override = cosmic_applet_config
+            .get(TIME_FORMAT_OVERRIDE)
             .unwrap_or_else(|err| {
                 if err.is_err() {
-                    error!(?err, "Failed to read config 'military_time'");
+                    error!(?err, "Failed to read config 'time_format_override'");
                 }
 
-                let default = get_locale_default_24h();
-                let _ = cosmic_applet_config.set("military_time", default);
-                default
+                // Keep a clock which was chosen before the override existed.
+                let time_format = match cosmic_applet_config.get::<bool>(MILITARY_TIME) {
+                    Ok(military) if military != locale_default_24h => {
+                        if military {
+                            TimeFormatOverride::Force24h
+                        } else {
+                            TimeFormatOverride::Force12h
+                        }
+                    }
+                    _ => TimeFormatOverride::FollowLocale,
+                };
+
+                let _ = cosmic_applet_config.set(TIME_FORMAT_OVERRIDE, time_format);
+                let _ = cosmic_applet_config
+                    .set(MILITARY_TIME, time_format.is_24h(|| locale_default_24h));
+                time_format
             });
 
         let show_seconds = cosmic_applet_config
@@ -74,14 +250,14 @@ impl Default for Page {
             });
 
         let first_day_of_week = cosmic_applet_config
-            .get("first_day_of_week")
+            .get(FIRST_DAY_OF_WEEK)
             .unwrap_or_else(|err| {
                 if err.is_err() {
                     error!(?err, "Failed to read config 'first_day_of_week'");
                 }
 
                 let default = get_locale_default_first_day();
-                let _ = cosmic_applet_config.set("first_day_of_week", default);
+                let _ = cosmic_applet_config.set(FIRST_DAY_OF_WEEK, default);
                 default
             });
 
@@ -95,20 +271,80 @@ impl Default for Page {
                 true
             });
 
+        let settings_config = cosmic_config::Config::new("com.system76.CosmicSettings", 1)
+            .inspect_err(|err| error!(?err, "Failed to open the settings config"))
+            .ok();
+
+        let pinned_timezones = settings_config
+            .as_ref()
+            .and_then(|config| {
+                config
+                    .get(PINNED_TIMEZONES)
+                    .inspect_err(|err| {
+                        if err.is_err() {
+                            error!(?err, "Failed to read config 'pinned_timezones'");
+                        }
+                    })
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        let secondary_time_locale = cosmic_applet_config
+            .get(SECONDARY_TIME_LOCALE)
+            .unwrap_or_else(|err| {
+                if err.is_err() {
+                    error!(?err, "Failed to read config 'secondary_time_locale'");
+                }
+
+                None
+            });
+
+        let (secondary_locale_name, secondary_locale_24h) = secondary_time_locale
+            .as_deref()
+            .map(describe_secondary_locale)
+            .unwrap_or_default();
+
         Self {
             entity: page::Entity::null(),
             cosmic_applet_config,
+            settings_config,
             first_day_of_week,
             formatted_date: String::new(),
             local_time: None,
-            military_time,
+            time_format_override,
+            locale_default_24h,
             show_seconds,
+            local_rtc: false,
             ntp_enabled: false,
+            ntp_synchronized: false,
+            ntp_accuracy: None,
             show_date_in_top_panel,
             timezone: None,
             timezone_context: false,
             timezone_list: Vec::new(),
+            timezone_labels: Vec::new(),
             timezone_search: String::new(),
+            timezone_offset: String::new(),
+            timezone_abbreviation: String::new(),
+            ntp_server_input: String::new(),
+            ntp_server_error: None,
+            suggested_locale: None,
+            pinned_timezones,
+            world_clock_now: jiff::Timestamp::now(),
+            conversion_input: String::new(),
+            conversion_result: None,
+            ical_timezone: None,
+            timezone_source: TimezoneSource::Unknown,
+            tzdata_version: None,
+            secondary_time_locale,
+            secondary_locale_name,
+            secondary_locale_24h,
+            secondary_formatted_date: String::new(),
+            secondary_locale_context: false,
+            secondary_locales: Vec::new(),
+            secondary_locale_search: String::new(),
+            country_timezones: BTreeMap::new(),
+            country_timezones_expanded: false,
         }
     }
 }
@@ -153,20 +389,68 @@ impl page::Page<crate::pages::Message> for Page {
 
             let can_ntp = timedate_proxy.can_ntp().await.unwrap_or_default();
             let ntp_enabled = can_ntp && timedate_proxy.ntp().await.unwrap_or_default();
-            let timezone_list = timedate_proxy.list_timezones().await.unwrap_or_default();
+            let ntp_synchronized = ntp_enabled
+                && timedate_proxy
+                    .inner()
+                    .get_property::<bool>("NTPSynchronized")
+                    .await
+                    .unwrap_or_default();
+            let ntp_accuracy = if ntp_synchronized {
+                get_ntp_accuracy().await
+            } else {
+                None
+            };
+            let (timezone_list, timezone_source) =
+                timezones_with_source(timedate_proxy.list_timezones().await.unwrap_or_default())
+                    .await;
+            let tzdata_version = get_tzdata_version().await;
+            let country_timezones = read_country_timezones().await;
+            let local_rtc = timedate_proxy.local_rtc().await.unwrap_or_default();
 
             let timezone = timedate_proxy.timezone().await.unwrap_or_default();
 
             Message::Refresh(Info {
+                local_rtc,
                 ntp_enabled,
+                ntp_synchronized,
+                ntp_accuracy,
                 timezone_id: timezone_list.iter().position(|tz| tz == &timezone),
                 timezone_list,
+                timezone_source,
+                tzdata_version,
+                country_timezones,
             })
         })
         .map(crate::pages::Message::DateAndTime)
     }
 
+    fn subscription(&self, _core: &cosmic::Core) -> Subscription<crate::pages::Message> {
+        if self.pinned_timezones.is_empty() {
+            return Subscription::none();
+        }
+
+        cosmic::iced::time::every(Duration::from_secs(1))
+            .map(|_| crate::pages::Message::DateAndTime(Message::Tick))
+    }
+
     fn context_drawer(&self) -> Option<ContextDrawer<'_, crate::pages::Message>> {
+        if self.secondary_locale_context {
+            let search = widget::search_input("", &self.secondary_locale_search)
+                .on_input(Message::SecondaryLocaleSearch)
+                .on_clear(Message::SecondaryLocaleSearch(String::new()))
+                .apply(Element::from)
+                .map(crate::pages::Message::DateAndTime);
+
+            return Some(
+                cosmic::app::context_drawer(
+                    self.secondary_locale_context_view(),
+                    crate::pages::Message::CloseContextDrawer,
+                )
+                .title(fl!("time-date", "secondary-locale"))
+                .header(search),
+            );
+        }
+
         if self.timezone_context {
             let search = widget::search_input("", &self.timezone_search)
                 .on_input(Message::TimezoneSearch)
@@ -194,15 +478,75 @@ impl Page {
         match message {
             Message::TimezoneContext => {
                 self.timezone_search.clear();
+                self.secondary_locale_context = false;
                 self.timezone_context = true;
                 return cosmic::task::message(crate::app::Message::OpenContextDrawer(self.entity));
             }
 
-            Message::MilitaryTime(enable) => {
-                self.military_time = enable;
+            Message::SecondaryLocaleContext => {
+                self.secondary_locale_search.clear();
+                self.timezone_context = false;
+                self.secondary_locale_context = true;
+
+                let open =
+                    cosmic::task::message(crate::app::Message::OpenContextDrawer(self.entity));
+
+                if !self.secondary_locales.is_empty() {
+                    return open;
+                }
+
+                let display = locale().unwrap_or(Locale::UNKNOWN);
+                let load = cosmic::Task::future(async move {
+                    Message::SecondaryLocales(shared_config::installed_regions(display).await)
+                })
+                .map(crate::pages::Message::DateAndTime)
+                .map(crate::Message::PageMessage);
+
+                return Task::batch([open, load]);
+            }
+
+            Message::SecondaryLocales(locales) => {
+                self.secondary_locales = locales;
+            }
+
+            Message::SecondaryLocaleSearch(search) => {
+                self.secondary_locale_search = search;
+            }
+
+            Message::SecondaryTimeLocale(locale) => {
+                if let Err(err) = self
+                    .cosmic_applet_config
+                    .set(SECONDARY_TIME_LOCALE, &locale)
+                {
+                    error!(?err, "Failed to set config 'secondary_time_locale'");
+                }
+
+                (self.secondary_locale_name, self.secondary_locale_24h) = locale
+                    .as_deref()
+                    .map(describe_secondary_locale)
+                    .unwrap_or_default();
+                self.secondary_time_locale = locale;
                 self.update_local_time();
+                self.secondary_locale_context = false;
+                return cosmic::task::message(crate::pages::Message::CloseContextDrawer);
+            }
 
-                if let Err(err) = self.cosmic_applet_config.set("military_time", enable) {
+            Message::TimeFormatOverride(time_format) => {
+                self.time_format_override = time_format;
+                self.update_local_time();
+
+                if let Err(err) = self
+                    .cosmic_applet_config
+                    .set(TIME_FORMAT_OVERRIDE, time_format)
+                {
+                    error!(?err, "Failed to set config 'time_format_override'");
+                }
+
+                // The time applet only reads whether to use a 24-hour clock.
+                if let Err(err) = self
+                    .cosmic_applet_config
+                    .set(MILITARY_TIME, self.locale_24h_override())
+                {
                     error!(?err, "Failed to set config 'military_time'");
                 }
             }
@@ -219,7 +563,7 @@ impl Page {
             Message::FirstDayOfWeek(weekday) => {
                 self.first_day_of_week = weekday;
 
-                if let Err(err) = self.cosmic_applet_config.set("first_day_of_week", weekday) {
+                if let Err(err) = self.cosmic_applet_config.set(FIRST_DAY_OF_WEEK, weekday) {
                     error!(?err, "Failed to set config 'first_day_of_week'");
                 }
             }
@@ -241,6 +585,10 @@ impl Page {
 
             Message::Timezone(timezone_id) => {
                 self.timezone = Some(timezone_id);
+                self.ical_timezone = None;
+                self.suggested_locale = None;
+                self.timezone_offset = self.detected_timezone_offset();
+                self.timezone_abbreviation = self.timezone_abbreviation();
 
                 if let Some(timezone) = self.timezone_list.get(timezone_id).cloned() {
                     return cosmic::Task::future(async move {
@@ -268,6 +616,199 @@ impl Page {
                 }
             }
 
+            Message::NtpServerInput(text) => {
+                self.ntp_server_input = text;
+                self.ntp_server_error = None;
+            }
+
+            Message::NtpServerInvalid(validation) => {
+                self.ntp_server_error = Some(validation);
+            }
+
+            Message::SetNtpServer(host) => {
+                self.ntp_server_error = None;
+                let host = host.trim().to_owned();
+
+                return cosmic::Task::future(async move {
+                    let validation = {
+                        let host = host.clone();
+                        tokio::task::spawn_blocking(move || validate_ntp_hostname(&host))
+                            .await
+                            .unwrap_or(NtpHostnameValidation::InvalidHostname)
+                    };
+
+                    if validation != NtpHostnameValidation::Valid {
+                        return Message::NtpServerInvalid(validation);
+                    }
+
+                    if let Err(why) = set_ntp_server(&host).await {
+                        tracing::error!(?why, host, "failed to set NTP server");
+                    }
+
+                    Message::None
+                })
+                .map(crate::pages::Message::DateAndTime)
+                .map(crate::Message::PageMessage);
+            }
+
+            Message::PinTimezone(timezone_id) => {
+                let Some(timezone) = self.timezone_list.get(timezone_id) else {
+                    return Task::none();
+                };
+
+                if let Some(pos) = self.pinned_timezones.iter().position(|tz| tz == timezone) {
+                    self.pinned_timezones.remove(pos);
+                } else {
+                    self.pinned_timezones.push(timezone.clone());
+                }
+
+                if let Some(config) = self.settings_config.as_ref()
+                    && let Err(err) = config.set(PINNED_TIMEZONES, &self.pinned_timezones)
+                {
+                    error!(?err, "Failed to set config 'pinned_timezones'");
+                }
+            }
+
+            #[cfg(feature = "xdg-portal")]
+            Message::ImportIcalTimezone => {
+                return cosmic::Task::future(async {
+                    let path = cosmic::dialog::file_chooser::open::Dialog::new()
+                        .title(fl!("time-zone", "ical-import"))
+                        .filter(cosmic::dialog::file_chooser::FileFilter::glob(
+                            cosmic::dialog::file_chooser::FileFilter::new("iCalendar"),
+                            "*.ics",
+                        ))
+                        .modal(false)
+                        .open_file()
+                        .await
+                        .ok()
+                        .and_then(|response| response.url().to_file_path().ok());
+
+                    match path {
+                        Some(path) => Message::IcalTimezone(parse_vtimezone(&path).await),
+                        None => Message::None,
+                    }
+                })
+                .map(crate::pages::Message::DateAndTime)
+                .map(crate::Message::PageMessage);
+            }
+
+            #[cfg(feature = "xdg-portal")]
+            Message::IcalTimezone(result) => {
+                self.ical_timezone = Some(result.and_then(|tzid| {
+                    self.timezone_list
+                        .iter()
+                        .position(|timezone| *timezone == tzid)
+                        .ok_or(IcalError::UnknownTimezone(tzid))
+                }));
+            }
+
+            Message::DismissIcalTimezone => {
+                self.ical_timezone = None;
+            }
+
+            Message::ToggleCountryTimezones => {
+                self.country_timezones_expanded = !self.country_timezones_expanded;
+            }
+
+            Message::SwitchHardwareClockToUtc => {
+                self.local_rtc = false;
+
+                tokio::task::spawn(async move {
+                    let client = match zbus::Connection::system().await {
+                        Ok(client) => client,
+                        Err(why) => {
+                            tracing::error!(?why, "zbus client error");
+                            return;
+                        }
+                    };
+
+                    let timedate_proxy = match TimeDateProxy::new(&client).await {
+                        Ok(timedate_proxy) => timedate_proxy,
+                        Err(why) => {
+                            tracing::error!(?why, "zbus client error");
+                            return;
+                        }
+                    };
+
+                    if let Err(why) = timedate_proxy.set_local_rtc(false, false, true).await {
+                        tracing::error!(?why, "failed to set the hardware clock to UTC");
+                    }
+                });
+            }
+
+            Message::Tick => {
+                self.world_clock_now = jiff::Timestamp::now();
+                self.update_local_time();
+            }
+
+            Message::ConversionInput(text) => {
+                self.conversion_input = text;
+                self.conversion_result = None;
+            }
+
+            Message::ConversionSubmit => {
+                let Some(from_tz) = self.timezone.and_then(|id| self.timezone_list.get(id)) else {
+                    return Task::none();
+                };
+
+                let Ok(local_time) =
+                    jiff::civil::Time::strptime("%H:%M", self.conversion_input.trim())
+                else {
+                    self.conversion_result = Some(fl!("time-zone", "convert-invalid"));
+                    return Task::none();
+                };
+
+                self.conversion_result = Some(
+                    self.describe_conversion(local_time, from_tz, "UTC")
+                        .unwrap_or_else(|| fl!("time-zone", "convert-invalid")),
+                );
+            }
+
+            Message::CreateDstReminder => {
+                let Some(timezone) = self.timezone.and_then(|id| self.timezone_list.get(id)) else {
+                    return Task::none();
+                };
+
+                let Some(&(timestamp, forward)) =
+                    next_dst_transitions(timezone, jiff::Timestamp::now(), 1).first()
+                else {
+                    return Task::none();
+                };
+
+                // Unlike `/tmp`, the runtime directory is only writable by the user.
+                let Some(path) = dirs::runtime_dir().map(|dir| dir.join(DST_REMINDER_FILE)) else {
+                    tracing::error!("XDG_RUNTIME_DIR is not set; cannot write DST reminder");
+                    return Task::none();
+                };
+
+                let summary = if forward {
+                    fl!("time-zone", "dst-reminder-forward")
+                } else {
+                    fl!("time-zone", "dst-reminder-back")
+                };
+
+                let event = dst_reminder_ical(timestamp, &summary);
+
+                return cosmic::Task::future(async move {
+                    match tokio::fs::write(&path, event).await {
+                        Ok(()) => Message::OpenFile(path),
+                        Err(why) => {
+                            tracing::error!(?why, "failed to write DST reminder");
+                            Message::None
+                        }
+                    }
+                })
+                .map(crate::pages::Message::DateAndTime)
+                .map(crate::Message::PageMessage);
+            }
+
+            Message::OpenFile(path) => {
+                if let Err(why) = std::process::Command::new("xdg-open").arg(&path).spawn() {
+                    tracing::error!(?why, ?path, "failed to open file");
+                }
+            }
+
             Message::Error(why) => {
                 tracing::error!(why, "failed to set timezone");
                 self.timezone_context = false;
@@ -278,17 +819,61 @@ impl Page {
                 self.set_ntp(true);
                 self.update_local_time();
                 self.timezone_context = false;
-                return cosmic::task::message(crate::pages::Message::CloseContextDrawer);
+                return cosmic::Task::batch([
+                    self.suggest_language_from_timezone(),
+                    cosmic::task::message(crate::pages::Message::CloseContextDrawer),
+                ]);
             }
 
             Message::Refresh(info) => {
+                self.local_rtc = info.local_rtc;
                 self.ntp_enabled = info.ntp_enabled;
+                self.ntp_synchronized = info.ntp_synchronized;
+                self.ntp_accuracy = info.ntp_accuracy;
                 self.timezone_list = info.timezone_list;
                 self.timezone = info.timezone_id;
+                // The imported time zone is an index into the list being replaced.
+                self.ical_timezone = None;
+                self.timezone_source = info.timezone_source;
+                self.tzdata_version = info.tzdata_version;
+                self.country_timezones = info.country_timezones;
+                self.timezone_labels = self.format_timezone_name_in_locale();
+                self.locale_default_24h = get_locale_default_24h();
 
                 self.update_local_time();
             }
 
+            #[cfg(feature = "page-region")]
+            Message::LocaleSuggestion(locale) => {
+                self.suggested_locale = locale.map(|locale| {
+                    let language = shared_config::language_autonym(locale)
+                        .unwrap_or_else(|| language_code(locale).to_owned());
+
+                    (locale, language)
+                });
+            }
+
+            Message::AcceptLocaleSuggestion => {
+                #[cfg(feature = "page-region")]
+                if let Some((locale, _)) = self.suggested_locale.take() {
+                    return cosmic::Task::future(async move {
+                        if let Err(why) =
+                            super::region::set_system_language(locale.to_owned()).await
+                        {
+                            tracing::error!(?why, locale, "failed to switch system language");
+                        }
+
+                        Message::None
+                    })
+                    .map(crate::pages::Message::DateAndTime)
+                    .map(crate::Message::PageMessage);
+                }
+            }
+
+            Message::DismissLocaleSuggestion => {
+                self.suggested_locale = None;
+            }
+
             Message::Surface(a) => {
                 return cosmic::task::message(crate::app::Message::Surface(a));
             }
@@ -328,12 +913,70 @@ impl Page {
 
         let search_input = &self.timezone_search.trim().to_lowercase();
 
-        for (id, timezone) in self.timezone_list.iter().enumerate() {
-            if search_input.is_empty() || timezone.to_lowercase().contains(search_input) {
+        for (id, label) in self.timezone_labels.iter().enumerate() {
+            if search_input.is_empty() || label.to_lowercase().contains(search_input) {
+                let pinned = self.pinned_timezones.contains(&self.timezone_list[id]);
+
+                list = list.add(
+                    widget::row::with_capacity(2)
+                        .push(
+                            selection_context_item(
+                                label,
+                                Some(id) == self.timezone,
+                                Message::Timezone(id),
+                            )
+                            .width(Length::Fill),
+                        )
+                        .push(
+                            widget::button::icon(widget::icon::from_name(if pinned {
+                                "view-pin-symbolic"
+                            } else {
+                                "list-add-symbolic"
+                            }))
+                            .selected(pinned)
+                            .on_press(Message::PinTimezone(id)),
+                        )
+                        .align_y(Alignment::Center),
+                );
+            }
+        }
+
+        let tzdata = match self.tzdata_version {
+            Some(ref version) => format!("tzdata {version}"),
+            None => "tzdata".to_owned(),
+        };
+
+        let source = match self.timezone_source {
+            TimezoneSource::Systemd => Some(fl!("time-zone", "source-systemd")),
+            TimezoneSource::Tzdata => Some(fl!("time-zone", "source-tzdata", tzdata = tzdata)),
+            TimezoneSource::Both => Some(fl!("time-zone", "source-both", tzdata = tzdata)),
+            TimezoneSource::Unknown => None,
+        };
+
+        widget::column::with_capacity(2)
+            .spacing(cosmic::theme::spacing().space_s)
+            .push(list)
+            .push_maybe(source.map(widget::text::caption))
+            .apply(Element::from)
+            .map(crate::pages::Message::DateAndTime)
+    }
+
+    /// Installed locales to show the current time in, with an entry to show none.
+    fn secondary_locale_context_view(&self) -> Element<'_, crate::pages::Message> {
+        let search_input = &self.secondary_locale_search.trim().to_lowercase();
+
+        let mut list = widget::list_column().add(selection_context_item(
+            &*SECONDARY_LOCALE_NONE,
+            self.secondary_time_locale.is_none(),
+            Message::SecondaryTimeLocale(None),
+        ));
+
+        for (lang_code, region_name) in &self.secondary_locales {
+            if search_input.is_empty() || region_name.to_lowercase().contains(search_input) {
                 list = list.add(selection_context_item(
-                    timezone,
-                    Some(id) == self.timezone,
-                    Message::Timezone(id),
+                    region_name,
+                    self.secondary_time_locale.as_ref() == Some(lang_code),
+                    Message::SecondaryTimeLocale(Some(lang_code.clone())),
                 ));
             }
         }
@@ -342,50 +985,386 @@ impl Page {
             .map(crate::pages::Message::DateAndTime)
     }
 
+    /// The current time formatted with the conventions of the secondary locale, if one is set.
+    fn show_time_in_secondary_locale(&self) -> Option<String> {
+        let locale = shared_config::parse_locale(self.secondary_time_locale.as_deref()?)?;
+        let time = self.local_time.as_ref()?;
+
+        Some(format_date_in_locale(
+            locale,
+            time,
+            self.time_format_override
+                .is_24h(|| self.secondary_locale_24h),
+            self.show_seconds,
+        ))
+    }
+
+    /// Whether times use a 24-hour clock, following the locale unless a clock is forced.
+    fn locale_24h_override(&self) -> bool {
+        self.time_format_override.is_24h(|| self.locale_default_24h)
+    }
+
+    /// The current time, city, and UTC offset of each pinned timezone.
+    fn world_clock_widget(&self) -> Element<'_, Message> {
+        let precision = if self.show_seconds {
+            TimePrecision::Second
+        } else {
+            TimePrecision::Minute
+        };
+
+        let formatter = time_of_day_formatter(self.locale_24h_override(), precision);
+
+        let mut grid = widget::column::with_capacity(self.pinned_timezones.len());
+
+        for timezone in &self.pinned_timezones {
+            let Ok(tz) = jiff::tz::TimeZone::get(timezone) else {
+                continue;
+            };
+
+            let offset = format_utc_offset(tz.to_offset(self.world_clock_now));
+            let time = formatter
+                .as_ref()
+                .zip(icu_time(self.world_clock_now.to_zoned(tz).time()))
+                .map(|(formatter, time)| formatter.format(&time).to_string())
+                .unwrap_or_default();
+
+            grid = grid.push(
+                widget::row::with_capacity(3)
+                    .push(widget::text::body(timezone_city(timezone)).width(Length::Fill))
+                    .push(widget::text::caption(offset))
+                    .push(widget::text::body(time).font(cosmic::font::bold()))
+                    .spacing(cosmic::theme::spacing().space_s)
+                    .align_y(Alignment::Center),
+            );
+        }
+
+        settings::item_row(vec![grid.spacing(4).into()]).into()
+    }
+
+    /// Labels each timezone ID with its generic name in the user's language, such as
+    /// `America/Los_Angeles (Pacific Time)`.
+    fn format_timezone_name_in_locale(&self) -> Vec<String> {
+        let formatter = locale().ok().and_then(|locale| {
+            NoCalendarFormatter::try_new(
+                DateTimeFormatterPreferences::from(locale),
+                fieldsets::zone::GenericLong,
+            )
+            .ok()
+        });
+
+        let Some(formatter) = formatter else {
+            return self.timezone_list.clone();
+        };
+
+        // Generic names depend on the metazone in effect at a given time.
+        let local_time = update_local_time();
+        let now = DateTime {
+            date: local_time.date.to_iso(),
+            time: local_time.time,
+        };
+        let parser = IanaParser::new();
+
+        self.timezone_list
+            .iter()
+            .map(|timezone| {
+                let zone = parser
+                    .parse(timezone)
+                    .without_offset()
+                    .at_date_time_iso(now);
+
+                format!("{timezone} ({})", formatter.format(&zone))
+            })
+            .collect()
+    }
+
+    /// Describes whether the clock has been synchronized over NTP, and how accurately.
+    fn ntp_sync_status_indicator(&self) -> Option<String> {
+        if !self.ntp_enabled {
+            return None;
+        }
+
+        Some(match (self.ntp_synchronized, self.ntp_accuracy) {
+            (true, Some(accuracy)) => fl!(
+                "time-date",
+                "ntp-synchronized-accuracy",
+                accuracy = accuracy.as_millis().to_string()
+            ),
+            (true, None) => fl!("time-date", "ntp-synchronized"),
+            (false, _) => fl!("time-date", "ntp-waiting"),
+        })
+    }
+
+    /// The number of timezones in the selected timezone's country, or `0` if it is unknown.
+    fn locale_time_zone_count_for_country(&self) -> usize {
+        self.timezone
+            .and_then(|id| self.timezone_list.get(id))
+            .and_then(|timezone| self.country_for_timezone(timezone))
+            .map_or(0, |country| self.timezones_for_country(country).len())
+    }
+
+    /// The timezones of a country, such as `Europe/Madrid` and `Atlantic/Canary` for `ES`.
+    fn timezones_for_country(&self, country_code: &str) -> Vec<&str> {
+        self.country_timezones
+            .get(country_code)
+            .map_or_else(Vec::new, |timezones| {
+                timezones.iter().map(String::as_str).collect()
+            })
+    }
+
+    /// The country of a timezone, by ISO 3166-1 alpha-2 code.
+    fn country_for_timezone(&self, tz: &str) -> Option<&str> {
+        self.country_timezones
+            .iter()
+            .find(|(_, timezones)| timezones.iter().any(|timezone| timezone == tz))
+            .map(|(country, _)| country.as_str())
+    }
+
+    /// Suggests the predominant language of the selected timezone's country, if the system
+    /// language differs from it.
+    fn suggest_language_from_timezone(&self) -> Task<crate::app::Message> {
+        #[cfg(feature = "page-region")]
+        if let Some(locale) = self
+            .timezone
+            .and_then(|id| self.timezone_list.get(id))
+            .and_then(|timezone| default_locale_for_timezone(timezone))
+        {
+            return cosmic::Task::future(async move {
+                let current = super::region::current_system_lang()
+                    .await
+                    .unwrap_or_default();
+
+                Message::LocaleSuggestion(
+                    (language_code(&current) != language_code(locale)).then_some(locale),
+                )
+            })
+            .map(crate::pages::Message::DateAndTime)
+            .map(crate::Message::PageMessage);
+        }
+
+        Task::none()
+    }
+
+    fn detected_timezone_offset(&self) -> String {
+        self.timezone
+            .and_then(|id| self.timezone_list.get(id))
+            .and_then(|timezone| jiff::tz::TimeZone::get(timezone).ok())
+            .map(|timezone| format_utc_offset(timezone.to_offset(jiff::Timestamp::now())))
+            .unwrap_or_default()
+    }
+
+    fn timezone_abbreviation(&self) -> String {
+        let Some(timezone) = self
+            .timezone
+            .and_then(|id| self.timezone_list.get(id))
+            .and_then(|timezone| jiff::tz::TimeZone::get(timezone).ok())
+        else {
+            return String::new();
+        };
+
+        let info = timezone.to_offset_info(jiff::Timestamp::now());
+        let abbreviation = info.abbreviation();
+
+        // Zones without an abbreviation use their offset, which is already displayed.
+        if abbreviation.starts_with(['+', '-']) {
+            String::new()
+        } else {
+            abbreviation.to_owned()
+        }
+    }
+
+    /// Describes the next occurrence of a time in one timezone as the time in another,
+    /// such as `9:00 AM Tokyo = 12:00 AM UTC`.
+    fn describe_conversion(
+        &self,
+        time: jiff::civil::Time,
+        from_tz: &str,
+        to_tz: &str,
+    ) -> Option<String> {
+        let converted = convert_time(time, from_tz, to_tz, jiff::Timestamp::now())?;
+        let military = self.locale_24h_override();
+
+        Some(format!(
+            "{} {} = {} {}",
+            format_time_of_day(time, military)?,
+            timezone_city(from_tz),
+            format_time_of_day(converted, military)?,
+            timezone_city(to_tz)
+        ))
+    }
+
     fn update_local_time(&mut self) {
         self.local_time = Some(update_local_time());
+        self.timezone_offset = self.detected_timezone_offset();
+        self.timezone_abbreviation = self.timezone_abbreviation();
 
         self.formatted_date = match self.local_time {
-            Some(ref time) => format_date(time, self.military_time, self.show_seconds),
+            Some(ref time) => format_date(time, self.locale_24h_override(), self.show_seconds),
             None => fl!("unknown"),
-        }
+        };
+
+        self.secondary_formatted_date = self.show_time_in_secondary_locale().unwrap_or_default();
     }
 }
 
 #[derive(Clone, Debug)]
 pub enum Message {
+    AcceptLocaleSuggestion,
+    ConversionInput(String),
+    ConversionSubmit,
+    CreateDstReminder,
+    DismissIcalTimezone,
+    DismissLocaleSuggestion,
     Error(String),
-    MilitaryTime(bool),
+    #[cfg(feature = "xdg-portal")]
+    IcalTimezone(Result<String, IcalError>),
+    #[cfg(feature = "xdg-portal")]
+    ImportIcalTimezone,
+    #[cfg(feature = "page-region")]
+    LocaleSuggestion(Option<&'static str>),
+    OpenFile(PathBuf),
+    PinTimezone(usize),
+    SecondaryLocaleContext,
+    SecondaryLocaleSearch(String),
+    SecondaryLocales(Vec<(String, String)>),
+    SecondaryTimeLocale(Option<String>),
+    NtpServerInput(String),
+    NtpServerInvalid(NtpHostnameValidation),
     ShowSeconds(bool),
     None,
     FirstDayOfWeek(usize),
     Refresh(Info),
+    SetNtpServer(String),
     ShowDate(bool),
+    SwitchHardwareClockToUtc,
+    Tick,
+    TimeFormatOverride(TimeFormatOverride),
     Timezone(usize),
     TimezoneContext,
     TimezoneSearch(String),
+    ToggleCountryTimezones,
     UpdateTime,
     Surface(surface::Action),
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NtpHostnameValidation {
+    Valid,
+    EmptyPool,
+    InvalidHostname,
+}
+
+impl NtpHostnameValidation {
+    fn error_message(self) -> Option<String> {
+        match self {
+            Self::Valid => None,
+            Self::EmptyPool => Some(fl!("time-date", "ntp-server-empty")),
+            Self::InvalidHostname => Some(fl!("time-date", "ntp-server-invalid")),
+        }
+    }
+}
+
 impl page::AutoBind<crate::pages::Message> for Page {}
 
 fn date() -> Section<crate::pages::Message> {
     crate::slab!(descriptions {
         title = fl!("time-date");
+        ntp_server = fl!("time-date", "ntp-server");
+        ntp_status = fl!("time-date", "ntp-status");
+        hardware_clock = fl!("time-date", "hardware-clock");
+        utc = fl!("time-date", "hardware-clock-utc");
+        local = fl!("time-date", "hardware-clock-local");
+        switch_to_utc = fl!("time-date", "hardware-clock-switch");
     });
 
     Section::default()
         .title(fl!("time-date"))
         .descriptions(descriptions)
         .view::<Page>(move |_binder, page, section| {
-            settings::section()
-                .title(&section.title)
-                .add(
-                    settings::item::builder(&*section.descriptions[title])
-                        .description(fl!("time-date", "auto-ntp"))
-                        .control(widget::text::body(&page.formatted_date)),
+            let mut content = settings::section().title(&section.title).add(
+                settings::item::builder(&*section.descriptions[title])
+                    .description(fl!("time-date", "auto-ntp"))
+                    .control(
+                        widget::row::with_capacity(2)
+                            .push(widget::text::body(&page.formatted_date))
+                            .push(
+                                widget::button::icon(widget::icon::from_name(
+                                    "emblem-system-symbolic",
+                                ))
+                                .extra_small()
+                                .on_press(Message::SecondaryLocaleContext),
+                            )
+                            .spacing(cosmic::theme::spacing().space_xxs)
+                            .align_y(Alignment::Center),
+                    ),
+            );
+
+            if page.secondary_time_locale.is_some() {
+                content = content.add(settings::item(
+                    &*page.secondary_locale_name,
+                    widget::text::body(&page.secondary_formatted_date),
+                ));
+            }
+
+            if !page.pinned_timezones.is_empty() {
+                content = content.add(page.world_clock_widget());
+            }
+
+            if let Some(status) = page.ntp_sync_status_indicator() {
+                content = content.add(settings::item(
+                    &*section.descriptions[ntp_status],
+                    widget::text::caption(status),
+                ));
+            }
+
+            content = content.add(settings::item(
+                &*section.descriptions[hardware_clock],
+                widget::text::body(if page.local_rtc {
+                    &*section.descriptions[local]
+                } else {
+                    &*section.descriptions[utc]
+                }),
+            ));
+
+            content.add(
+                settings::item::builder(&*section.descriptions[ntp_server]).control(
+                    widget::column::with_capacity(2)
+                        .push(
+                            widget::text_input("pool.ntp.org", &page.ntp_server_input)
+                                .on_input(Message::NtpServerInput)
+                                .on_submit(Message::SetNtpServer),
+                        )
+                        .push_maybe(
+                            page.ntp_server_error
+                                .and_then(NtpHostnameValidation::error_message)
+                                .map(|error| {
+                                    widget::text::caption(error).class(cosmic::theme::Text::Color(
+                                        cosmic::theme::active()
+                                            .cosmic()
+                                            .destructive_text_color()
+                                            .into(),
+                                    ))
+                                }),
+                        )
+                        .align_x(Alignment::End),
+                ),
+            );
+
+            let local_rtc_warning = page.local_rtc.then(|| {
+                crate::widget::notice_item(
+                    "dialog-warning-symbolic",
+                    fl!("time-date", "hardware-clock-warning"),
+                    vec![
+                        widget::button::standard(&section.descriptions[switch_to_utc])
+                            .on_press(Message::SwitchHardwareClockToUtc)
+                            .into(),
+                    ],
                 )
+            });
+
+            widget::column::with_capacity(2)
+                .push(content)
+                .push_maybe(local_rtc_warning)
+                .spacing(cosmic::theme::spacing().space_xxs)
                 .apply(cosmic::Element::from)
                 .map(crate::pages::Message::DateAndTime)
         })
@@ -393,6 +1372,8 @@ fn date() -> Section<crate::pages::Message> {
 
 fn format() -> Section<crate::pages::Message> {
     crate::slab!(descriptions {
+        follow_locale = fl!("time-format", "follow-locale");
+        twelve = fl!("time-format", "twelve");
         military = fl!("time-format", "twenty-four");
         show_seconds = fl!("time-format", "show-seconds");
         first = fl!("time-format", "first");
@@ -405,10 +1386,27 @@ fn format() -> Section<crate::pages::Message> {
         .view::<Page>(move |_binder, page, section| {
             settings::section()
                 .title(&section.title)
-                // 24-hour toggle
+                // 12 or 24-hour clock
+                .add(
+                    settings::item::builder(&section.descriptions[follow_locale]).radio(
+                        TimeFormatOverride::FollowLocale,
+                        Some(page.time_format_override),
+                        Message::TimeFormatOverride,
+                    ),
+                )
                 .add(
-                    settings::item::builder(&section.descriptions[military])
-                        .toggler(page.military_time, Message::MilitaryTime),
+                    settings::item::builder(&section.descriptions[twelve]).radio(
+                        TimeFormatOverride::Force12h,
+                        Some(page.time_format_override),
+                        Message::TimeFormatOverride,
+                    ),
+                )
+                .add(
+                    settings::item::builder(&section.descriptions[military]).radio(
+                        TimeFormatOverride::Force24h,
+                        Some(page.time_format_override),
+                        Message::TimeFormatOverride,
+                    ),
                 )
                 // Show seconds in time format
                 .add(
@@ -457,25 +1455,205 @@ fn format() -> Section<crate::pages::Message> {
 fn timezone() -> Section<crate::pages::Message> {
     crate::slab!(descriptions {
         time_zone = fl!("time-zone");
+        accept = fl!("time-zone", "switch-language");
+        remind = fl!("time-zone", "dst-reminder");
+        convert = fl!("time-zone", "convert");
+        ical_import = fl!("time-zone", "ical-import");
+        ical_use = fl!("time-zone", "ical-use");
+        ical_choose = fl!("time-zone", "ical-choose");
+        country_timezones = fl!("time-zone", "country-other");
+        country_select = fl!("time-zone", "country-select");
     });
 
     Section::default()
         .title(fl!("time-zone"))
         .descriptions(descriptions)
         .view::<Page>(move |_binder, page, section| {
-            settings::section()
+            let suggestion = page.suggested_locale.as_ref().map(|(_, language)| {
+                crate::widget::notice_item(
+                    "dialog-information-symbolic",
+                    fl!("timezone-language-suggestion", language = language.as_str()),
+                    vec![
+                        widget::button::standard(&section.descriptions[accept])
+                            .on_press(Message::AcceptLocaleSuggestion)
+                            .into(),
+                        widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                            .on_press(Message::DismissLocaleSuggestion)
+                            .into(),
+                    ],
+                )
+            });
+
+            let ical_timezone = page.ical_timezone.as_ref().map(|result| {
+                let dismiss =
+                    widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                        .on_press(Message::DismissIcalTimezone)
+                        .into();
+
+                match result {
+                    Ok(id) => crate::widget::notice_item(
+                        "dialog-information-symbolic",
+                        fl!(
+                            "time-zone",
+                            "ical-found",
+                            timezone = page.timezone_list[*id].as_str()
+                        ),
+                        vec![
+                            widget::button::standard(&section.descriptions[ical_use])
+                                .on_press(Message::Timezone(*id))
+                                .into(),
+                            dismiss,
+                        ],
+                    ),
+                    Err(why) => crate::widget::notice_item(
+                        "dialog-warning-symbolic",
+                        why.to_string(),
+                        vec![dismiss],
+                    ),
+                }
+            });
+
+            let next_transition = page
+                .timezone
+                .and_then(|id| page.timezone_list.get(id))
+                .and_then(|timezone| {
+                    let (timestamp, _) =
+                        *next_dst_transitions(timezone, jiff::Timestamp::now(), 1).first()?;
+                    let timezone = jiff::tz::TimeZone::get(timezone).ok()?;
+                    Some(
+                        timestamp
+                            .to_zoned(timezone)
+                            .strftime("%Y-%m-%d %H:%M")
+                            .to_string(),
+                    )
+                });
+
+            // Without NTP, nothing corrects the drift of the hardware clock.
+            let clock_drift = (!page.ntp_enabled).then(|| {
+                crate::widget::notice_item(
+                    "dialog-information-symbolic",
+                    fl!("time-zone", "clock-drift"),
+                    Vec::new(),
+                )
+            });
+
+            let dst_reminder = next_transition.map(|transition| {
+                settings::item(
+                    fl!("time-zone", "next-dst", date = transition.as_str()),
+                    widget::button::standard(&section.descriptions[remind])
+                        .on_press(Message::CreateDstReminder),
+                )
+            });
+
+            let country_timezone_count = page.locale_time_zone_count_for_country();
+
+            let mut content = settings::section()
                 .title(&section.title)
                 // Time zone select
                 .add(crate::widget::go_next_with_item(
                     &section.descriptions[time_zone],
-                    widget::text::body(
-                        page.timezone
-                            .map(|id| &*page.timezone_list[id])
-                            .unwrap_or_default(),
-                    )
-                    .wrapping(Wrapping::Word),
+                    widget::column::with_capacity(3)
+                        .push(
+                            widget::text::body(page.timezone.map_or(String::new(), |id| {
+                                let name = &page.timezone_list[id];
+
+                                if page.timezone_abbreviation.is_empty() {
+                                    name.clone()
+                                } else {
+                                    format!("{name} ({})", page.timezone_abbreviation)
+                                }
+                            }))
+                            .wrapping(Wrapping::Word),
+                        )
+                        .push(widget::text::caption(&page.timezone_offset))
+                        .push_maybe((country_timezone_count > 1).then(|| {
+                            widget::text::caption(fl!(
+                                "time-zone",
+                                "country-count",
+                                count = country_timezone_count
+                            ))
+                        }))
+                        .align_x(Alignment::End),
                     Message::TimezoneContext,
-                ))
+                ));
+
+            if country_timezone_count > 1 {
+                content = content.add(
+                    settings::item::builder(&*section.descriptions[country_timezones]).control(
+                        widget::button::icon(widget::icon::from_name(
+                            if page.country_timezones_expanded {
+                                "go-up-symbolic"
+                            } else {
+                                "go-down-symbolic"
+                            },
+                        ))
+                        .on_press(Message::ToggleCountryTimezones),
+                    ),
+                );
+            }
+
+            if page.country_timezones_expanded
+                && let Some(selected) = page.timezone.and_then(|id| page.timezone_list.get(id))
+                && let Some(country) = page.country_for_timezone(selected)
+            {
+                for timezone in page.timezones_for_country(country) {
+                    if timezone == selected {
+                        continue;
+                    }
+
+                    let Some(id) = page.timezone_list.iter().position(|tz| tz == timezone) else {
+                        continue;
+                    };
+
+                    let label = page
+                        .timezone_labels
+                        .get(id)
+                        .map_or(timezone, String::as_str);
+
+                    content = content.add(settings::item(
+                        label,
+                        widget::button::standard(&section.descriptions[country_select])
+                            .on_press(Message::Timezone(id)),
+                    ));
+                }
+            }
+
+            if let Some(dst_reminder) = dst_reminder {
+                content = content.add(dst_reminder);
+            }
+
+            if page.timezone.is_some() {
+                content = content.add(
+                    settings::item::builder(&*section.descriptions[convert]).control(
+                        widget::column::with_capacity(2)
+                            .push(
+                                widget::text_input("09:00", &page.conversion_input)
+                                    .on_input(Message::ConversionInput)
+                                    .on_submit(|_| Message::ConversionSubmit),
+                            )
+                            .push_maybe(
+                                page.conversion_result.as_deref().map(widget::text::caption),
+                            )
+                            .align_x(Alignment::End),
+                    ),
+                );
+            }
+
+            #[cfg(feature = "xdg-portal")]
+            {
+                content = content.add(settings::item(
+                    &*section.descriptions[ical_import],
+                    widget::button::standard(&section.descriptions[ical_choose])
+                        .on_press(Message::ImportIcalTimezone),
+                ));
+            }
+
+            widget::column::with_capacity(4)
+                .push(content)
+                .push_maybe(clock_drift)
+                .push_maybe(ical_timezone)
+                .push_maybe(suggestion)
+                .spacing(cosmic::theme::spacing().space_xxs)
                 .apply(cosmic::Element::from)
                 .map(crate::pages::Message::DateAndTime)
         })
@@ -498,6 +1676,15 @@ fn format_date(date: &DateTime<Gregorian>, military: bool, show_seconds: bool) -
         return String::new();
     };
 
+    format_date_in_locale(locale, date, military, show_seconds)
+}
+
+fn format_date_in_locale(
+    locale: Locale,
+    date: &DateTime<Gregorian>,
+    military: bool,
+    show_seconds: bool,
+) -> String {
     let mut prefs = DateTimeFormatterPreferences::from(locale);
     prefs.hour_cycle = Some(if military {
         HourCycle::H23
@@ -515,6 +1702,352 @@ fn format_date(date: &DateTime<Gregorian>, military: bool, show_seconds: bool) -
     dtf.format(date).to_string()
 }
 
+/// The predominant locale of the country in which a timezone is located.
+#[cfg(feature = "page-region")]
+fn default_locale_for_timezone(tz: &str) -> Option<&'static str> {
+    TIMEZONE_LOCALES
+        .iter()
+        .find(|(timezone, _)| *timezone == tz)
+        .map(|(_, locale)| *locale)
+}
+
+/// The timezones of each country, by ISO 3166-1 alpha-2 code, from the tzdata tables.
+async fn read_country_timezones() -> BTreeMap<String, Vec<String>> {
+    for path in ZONE_TABS {
+        if let Ok(contents) = tokio::fs::read_to_string(path).await {
+            return parse_zone_tab(&contents);
+        }
+    }
+
+    BTreeMap::new()
+}
+
+/// The timezones of each country in a `zone.tab` or `zone1970.tab` file. The latter lists
+/// several comma-separated countries for a timezone which they share.
+fn parse_zone_tab(contents: &str) -> BTreeMap<String, Vec<String>> {
+    let mut countries = BTreeMap::<String, Vec<String>>::new();
+
+    for line in contents.lines().filter(|line| !line.starts_with('#')) {
+        let mut fields = line.split('\t');
+        let (Some(codes), Some(_coordinates), Some(timezone)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+
+        for code in codes.split(',') {
+            countries
+                .entry(code.to_owned())
+                .or_default()
+                .push(timezone.to_owned());
+        }
+    }
+
+    countries
+}
+
+/// The language subtag of a POSIX locale, such as `de` for `de_DE.UTF-8`.
+#[cfg(feature = "page-region")]
+fn language_code(locale: &str) -> &str {
+    locale.split(['_', '.', '@']).next().unwrap_or(locale)
+}
+
+/// The daylight saving time transitions of a timezone after a time, and whether each one
+/// sets clocks forward.
+fn next_dst_transitions(
+    timezone: &str,
+    after: jiff::Timestamp,
+    count: usize,
+) -> Vec<(jiff::Timestamp, bool)> {
+    let Ok(timezone) = jiff::tz::TimeZone::get(timezone) else {
+        return Vec::new();
+    };
+
+    timezone
+        .following(after)
+        .filter_map(|transition| {
+            let timestamp = transition.timestamp();
+            let before = timezone.to_offset(
+                timestamp
+                    .checked_sub(jiff::SignedDuration::from_secs(1))
+                    .ok()?,
+            );
+
+            (before != transition.offset()).then(|| (timestamp, transition.offset() > before))
+        })
+        .take(count)
+        .collect()
+}
+
+/// The next time that a time of day occurs in a timezone, which is tomorrow if it has
+/// already passed today.
+fn next_occurrence_of_time(
+    time: jiff::civil::Time,
+    timezone: &jiff::tz::TimeZone,
+    now: jiff::Timestamp,
+) -> Option<jiff::Zoned> {
+    let now = now.to_zoned(timezone.clone());
+    let today = now
+        .date()
+        .to_datetime(time)
+        .to_zoned(timezone.clone())
+        .ok()?;
+
+    if today >= now {
+        Some(today)
+    } else {
+        now.date()
+            .tomorrow()
+            .ok()?
+            .to_datetime(time)
+            .to_zoned(timezone.clone())
+            .ok()
+    }
+}
+
+/// The time in another timezone at the next occurrence of a time in one timezone after `now`.
+fn convert_time(
+    time: jiff::civil::Time,
+    from_tz: &str,
+    to_tz: &str,
+    now: jiff::Timestamp,
+) -> Option<jiff::civil::Time> {
+    let from = jiff::tz::TimeZone::get(from_tz).ok()?;
+    let to = jiff::tz::TimeZone::get(to_tz).ok()?;
+
+    Some(
+        next_occurrence_of_time(time, &from, now)?
+            .with_time_zone(to)
+            .time(),
+    )
+}
+
+/// Formats a time of day in the locale's pattern for the given hour cycle, such as `9:00 AM`.
+fn format_time_of_day(time: jiff::civil::Time, military: bool) -> Option<String> {
+    let formatter = time_of_day_formatter(military, TimePrecision::Minute)?;
+
+    Some(formatter.format(&icu_time(time)?).to_string())
+}
+
+/// Formats times of day in the locale's pattern for the given hour cycle.
+fn time_of_day_formatter(
+    military: bool,
+    precision: TimePrecision,
+) -> Option<NoCalendarFormatter<fieldsets::T>> {
+    let mut prefs = DateTimeFormatterPreferences::from(locale().ok()?);
+    prefs.hour_cycle = Some(if military {
+        HourCycle::H23
+    } else {
+        HourCycle::H12
+    });
+
+    NoCalendarFormatter::try_new(prefs, fieldsets::T::short().with_time_precision(precision)).ok()
+}
+
+fn icu_time(time: jiff::civil::Time) -> Option<Time> {
+    Time::try_new(
+        time.hour() as u8,
+        time.minute() as u8,
+        time.second() as u8,
+        0,
+    )
+    .ok()
+}
+
+/// The city of a timezone ID, such as `New York` for `America/New_York`.
+fn timezone_city(timezone: &str) -> String {
+    timezone
+        .rsplit('/')
+        .next()
+        .unwrap_or(timezone)
+        .replace('_', " ")
+}
+
+/// The selectable timezones and where they come from. The timezones listed by
+/// systemd-timedated are used, and the tzdata database is only read if it lists none.
+async fn timezones_with_source(listed: Vec<String>) -> (Vec<String>, TimezoneSource) {
+    let tzdata = tokio::fs::read_to_string(TZDATA_ZI).await.ok();
+
+    if !listed.is_empty() {
+        let source = if tzdata.is_some() {
+            TimezoneSource::Both
+        } else {
+            TimezoneSource::Systemd
+        };
+
+        return (listed, source);
+    }
+
+    let timezones = tzdata
+        .as_deref()
+        .map(parse_tzdata_zones)
+        .unwrap_or_default();
+    if timezones.is_empty() {
+        (timezones, TimezoneSource::Unknown)
+    } else {
+        (timezones, TimezoneSource::Tzdata)
+    }
+}
+
+/// The zone and link names of a `tzdata.zi` file, sorted as systemd-timedated lists them.
+fn parse_tzdata_zones(contents: &str) -> Vec<String> {
+    let mut timezones: Vec<String> = contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+
+            match fields.next()? {
+                // `Z <name> ...` defines a zone.
+                "Z" => fields.next(),
+                // `L <target> <name>` links a name to a zone.
+                "L" => fields.nth(1),
+                _ => None,
+            }
+        })
+        .map(str::to_owned)
+        .collect();
+
+    timezones.sort_unstable();
+    timezones.dedup();
+    timezones
+}
+
+/// The release of the installed tzdata database, such as `2024a`.
+async fn get_tzdata_version() -> Option<String> {
+    if let Ok(contents) = tokio::fs::read_to_string(TZDATA_ZI).await
+        && let Some(version) = contents
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("# version "))
+    {
+        return Some(version.trim().to_owned());
+    }
+
+    tokio::fs::read_to_string("/usr/share/zoneinfo/+VERSION")
+        .await
+        .ok()
+        .map(|version| version.trim().to_owned())
+        .filter(|version| !version.is_empty())
+}
+
+/// Reads the IANA timezone of the first VTIMEZONE in an iCalendar file.
+#[cfg(feature = "xdg-portal")]
+async fn parse_vtimezone(path: &Path) -> Result<String, IcalError> {
+    let contents = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|why| IcalError::Io(why.to_string()))?;
+
+    vtimezone_tzid(&contents)
+}
+
+/// The TZID of the first VTIMEZONE in an iCalendar document, if it names an IANA timezone.
+#[cfg(feature = "xdg-portal")]
+fn vtimezone_tzid(contents: &str) -> Result<String, IcalError> {
+    // Long lines are folded by continuing them on lines starting with whitespace.
+    let unfolded = contents
+        .replace("\r\n ", "")
+        .replace("\r\n\t", "")
+        .replace("\n ", "")
+        .replace("\n\t", "");
+
+    let tzid = unfolded
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|line| !line.eq_ignore_ascii_case("BEGIN:VTIMEZONE"))
+        .take_while(|line| !line.eq_ignore_ascii_case("END:VTIMEZONE"))
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            let name = name.split(';').next()?;
+            name.eq_ignore_ascii_case("TZID")
+                .then(|| value.trim().to_owned())
+        })
+        .ok_or(IcalError::MissingTimezone)?;
+
+    if jiff::tz::TimeZone::get(&tzid).is_ok() {
+        Ok(tzid)
+    } else {
+        Err(IcalError::UnknownTimezone(tzid))
+    }
+}
+
+/// An iCalendar event which reminds the user of a daylight saving time transition.
+fn dst_reminder_ical(timestamp: jiff::Timestamp, summary: &str) -> String {
+    const ICAL_UTC: &str = "%Y%m%dT%H%M%SZ";
+
+    // Translations may contain characters which are special in iCalendar text values.
+    let summary = summary
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n");
+
+    [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        "PRODID:-//System76//COSMIC Settings//EN",
+        "BEGIN:VEVENT",
+        &format!("UID:{}-dst@cosmic-settings", timestamp.as_second()),
+        &format!("DTSTAMP:{}", jiff::Timestamp::now().strftime(ICAL_UTC)),
+        &format!("DTSTART:{}", timestamp.strftime(ICAL_UTC)),
+        &format!("SUMMARY:{summary}"),
+        "BEGIN:VALARM",
+        "ACTION:DISPLAY",
+        &format!("DESCRIPTION:{summary}"),
+        "TRIGGER:-P1D",
+        "END:VALARM",
+        "END:VEVENT",
+        "END:VCALENDAR",
+        "",
+    ]
+    .join("\r\n")
+}
+
+/// The root distance reported by systemd-timesyncd, which bounds the clock's error.
+async fn get_ntp_accuracy() -> Option<Duration> {
+    let output = tokio::process::Command::new("timedatectl")
+        .arg("timesync-status")
+        .output()
+        .await
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    // Formatted as `Root distance: 23.456ms (max: 5s)`.
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Root distance:"))
+        .and_then(|value| value.split_whitespace().next())
+        .and_then(parse_timespan)
+}
+
+/// Parses a systemd timespan with a single unit, such as `23.456ms`.
+fn parse_timespan(value: &str) -> Option<Duration> {
+    let split = value.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (number, unit) = value.split_at(split);
+    let number = number.parse::<f64>().ok()?;
+
+    let seconds = match unit {
+        "us" | "μs" => number / 1_000_000.0,
+        "ms" => number / 1_000.0,
+        "s" => number,
+        "min" => number * 60.0,
+        _ => return None,
+    };
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Formats a UTC offset as `UTC+HH:MM` or `UTC-HH:MM`.
+fn format_utc_offset(offset: jiff::tz::Offset) -> String {
+    let seconds = offset.seconds();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.unsigned_abs() / 60;
+
+    format!("UTC{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
 fn update_local_time() -> DateTime<Gregorian> {
     let now = jiff::Zoned::now();
 
@@ -525,39 +2058,290 @@ fn update_local_time() -> DateTime<Gregorian> {
     }
 }
 
+/// The region name of a secondary locale, and whether it uses a 24-hour clock by default.
+fn describe_secondary_locale(lang_code: &str) -> (String, bool) {
+    let name = locale()
+        .ok()
+        .and_then(|display| shared_config::RegionNames::try_new(&display))
+        .and_then(|names| names.of(lang_code))
+        .unwrap_or_else(|| lang_code.to_owned());
+
+    let default_24h = shared_config::parse_locale(lang_code)
+        .is_some_and(|locale| shared_config::default_24h(&locale));
+
+    (name, default_24h)
+}
+
 fn get_locale_default_24h() -> bool {
-    let Ok(locale) = locale() else { return false };
+    locale().is_ok_and(|locale| shared_config::default_24h(&locale))
+}
 
-    let test_time = DateTime {
-        date: Date::try_new_gregorian(2024, 1, 1).unwrap(),
-        time: Time::try_new(13, 0, 0, 0).unwrap(),
-    };
+fn get_locale_default_first_day() -> usize {
+    locale().map_or(6, |locale| shared_config::default_first_day(&locale))
+}
 
-    let prefs = DateTimeFormatterPreferences::from(locale);
-    let Ok(dtf) = DateTimeFormatter::try_new(prefs, fieldsets::T::medium()) else {
-        return false;
-    };
+/// Checks that a custom NTP server is a well-formed and resolvable host.
+///
+/// Resolving the host blocks, so this should not be called on the UI thread.
+fn validate_ntp_hostname(host: &str) -> NtpHostnameValidation {
+    let host = host.trim().trim_end_matches('.');
+
+    if host.is_empty() {
+        return NtpHostnameValidation::EmptyPool;
+    }
 
-    let formatted = dtf.format(&test_time).to_string();
+    let is_ip = host.parse::<std::net::IpAddr>().is_ok();
+    let is_hostname = host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+
+    if !is_ip && !is_hostname {
+        return NtpHostnameValidation::InvalidHostname;
+    }
 
-    // If we see "13" in the output, it's 24-hour format
-    // If we see "1" (but not "13"), it's 12-hour format
-    formatted.contains("13")
+    match (host, 123).to_socket_addrs() {
+        Ok(mut addrs) if addrs.next().is_some() => NtpHostnameValidation::Valid,
+        _ => NtpHostnameValidation::InvalidHostname,
+    }
 }
 
-fn get_locale_default_first_day() -> usize {
-    let Ok(locale) = locale() else { return 6 };
-    let Ok(week_info) = week::WeekInformation::try_new(week::WeekPreferences::from(&locale)) else {
-        return 6;
+/// Configures systemd-timesyncd to use the given server, and restarts it to apply.
+async fn set_ntp_server(host: &str) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    // The service manager is not `Send`, so only its restart command is held across awaits.
+    let restart = {
+        let timesyncd = crate::service_manager::create_default_service_manager(TIMESYNCD_SERVICE);
+        if !timesyncd.is_installed() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "systemd-timesyncd is not installed",
+            ));
+        }
+
+        timesyncd.restart_command()
     };
 
-    match week_info.first_weekday {
-        Weekday::Monday => 0,
-        Weekday::Tuesday => 1,
-        Weekday::Wednesday => 2,
-        Weekday::Thursday => 3,
-        Weekday::Friday => 4,
-        Weekday::Saturday => 5,
-        Weekday::Sunday => 6,
+    // Install the drop-in and restart the service in one step, so that only one password
+    // prompt is shown. The path is passed as $0, followed by the restart command.
+    let mut child = tokio::process::Command::new("pkexec")
+        .args([
+            "sh",
+            "-c",
+            "install -D -m 644 /dev/stdin \"$0\" && \"$@\"",
+            TIMESYNCD_DROP_IN,
+        ])
+        .args(&restart)
+        .stdin(std::process::Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(format!("[Time]\nNTP={host}\n").as_bytes())
+            .await?;
+    }
+
+    let status = child.wait().await?;
+    if !status.success() {
+        return Err(std::io::Error::other(format!(
+            "pkexec exited with {status}"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(value: &str) -> jiff::Timestamp {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_convert_time_uses_the_next_occurrence() {
+        let nine = jiff::civil::time(9, 0, 0, 0);
+
+        assert_eq!(
+            convert_time(nine, "Asia/Tokyo", "UTC", timestamp("2025-01-15T00:00:00Z")),
+            Some(jiff::civil::time(0, 0, 0, 0))
+        );
+
+        // 09:00 has already passed in New York, so the time is converted on the next day.
+        assert_eq!(
+            convert_time(
+                nine,
+                "America/New_York",
+                "UTC",
+                timestamp("2025-01-15T16:00:00Z")
+            ),
+            Some(jiff::civil::time(14, 0, 0, 0))
+        );
+        assert_eq!(
+            convert_time(
+                nine,
+                "America/New_York",
+                "UTC",
+                timestamp("2025-07-15T16:00:00Z")
+            ),
+            Some(jiff::civil::time(13, 0, 0, 0))
+        );
+
+        assert_eq!(
+            convert_time(nine, "Not/A_Zone", "UTC", timestamp("2025-01-15T00:00:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_next_dst_transitions() {
+        assert_eq!(
+            next_dst_transitions("Europe/Berlin", timestamp("2025-01-01T00:00:00Z"), 2),
+            [
+                (timestamp("2025-03-30T01:00:00Z"), true),
+                (timestamp("2025-10-26T01:00:00Z"), false),
+            ]
+        );
+
+        assert!(next_dst_transitions("UTC", timestamp("2025-01-01T00:00:00Z"), 2).is_empty());
+        assert!(
+            next_dst_transitions("Asia/Tokyo", timestamp("2025-01-01T00:00:00Z"), 2).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_dst_reminder_ical() {
+        let ical = dst_reminder_ical(
+            timestamp("2025-03-30T01:00:00Z"),
+            "Clocks change: set your clock forward",
+        );
+
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ical.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(ical.contains("\r\nUID:1743296400-dst@cosmic-settings\r\n"));
+        assert!(ical.contains("\r\nDTSTART:20250330T010000Z\r\n"));
+        assert!(ical.contains("\r\nSUMMARY:Clocks change: set your clock forward\r\n"));
+        assert!(ical.contains("\r\nTRIGGER:-P1D\r\n"));
+
+        let ical = dst_reminder_ical(
+            timestamp("2025-10-26T01:00:00Z"),
+            "Uhren umstellen; zurück, bitte",
+        );
+        assert!(ical.contains("\r\nSUMMARY:Uhren umstellen\\; zurück\\, bitte\r\n"));
+    }
+
+    #[cfg(feature = "xdg-portal")]
+    #[test]
+    fn test_vtimezone_tzid() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+                   VERSION:2.0\r\n\
+                   BEGIN:VTIMEZONE\r\n\
+                   TZID:Europe/Berlin\r\n\
+                   END:VTIMEZONE\r\n\
+                   END:VCALENDAR\r\n";
+        assert_eq!(vtimezone_tzid(ics).ok().as_deref(), Some("Europe/Berlin"));
+
+        // Folded lines and parameters on the TZID property.
+        let folded =
+            "BEGIN:VTIMEZONE\r\nTZID;X-LIC-LOCATION=x:America/\r\n New_York\r\nEND:VTIMEZONE\r\n";
+        assert_eq!(
+            vtimezone_tzid(folded).ok().as_deref(),
+            Some("America/New_York")
+        );
+
+        // Only the TZID within a VTIMEZONE counts.
+        let event = "BEGIN:VEVENT\nTZID:Asia/Tokyo\nEND:VEVENT\n";
+        assert!(matches!(
+            vtimezone_tzid(event),
+            Err(IcalError::MissingTimezone)
+        ));
+
+        let custom = "BEGIN:VTIMEZONE\nTZID:Custom Time\nEND:VTIMEZONE\n";
+        assert!(matches!(
+            vtimezone_tzid(custom),
+            Err(IcalError::UnknownTimezone(tzid)) if tzid == "Custom Time"
+        ));
+    }
+
+    #[cfg(feature = "xdg-portal")]
+    #[tokio::test]
+    async fn test_parse_vtimezone_reads_the_file() {
+        let path = std::env::temp_dir().join(format!(
+            "cosmic-settings-vtimezone-{}.ics",
+            std::process::id()
+        ));
+        std::fs::write(&path, "BEGIN:VTIMEZONE\nTZID:Asia/Tokyo\nEND:VTIMEZONE\n").unwrap();
+
+        let result = parse_vtimezone(&path).await;
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(result.ok().as_deref(), Some("Asia/Tokyo"));
+
+        assert!(matches!(
+            parse_vtimezone(Path::new("/nonexistent/calendar.ics")).await,
+            Err(IcalError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_tzdata_zones() {
+        let tzdata = "# version 2025b\n\
+                      R d 1916 o - Ap 30 23 1 S\n\
+                      Z Europe/Berlin 0:53:28 - LMT 1893 Ap\n\
+                      1 c CE%sT 1945 May 24 2\n\
+                      Z Etc/UTC 0 - UTC\n\
+                      L Etc/UTC UTC\n\
+                      L Europe/Berlin Arctic/Longyearbyen\n";
+
+        assert_eq!(
+            parse_tzdata_zones(tzdata),
+            ["Arctic/Longyearbyen", "Etc/UTC", "Europe/Berlin", "UTC"]
+        );
+        assert!(parse_tzdata_zones("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_zone_tab() {
+        let zone_tab = "# tzdb timezone descriptions\n\
+                        ES\t+4024-00341\tEurope/Madrid\tSpain (mainland)\n\
+                        ES\t+2806-01524\tAtlantic/Canary\tCanary Islands\n\
+                        CH,DE,LI\t+4723+00832\tEurope/Zurich\n";
+
+        let countries = parse_zone_tab(zone_tab);
+        assert_eq!(countries["ES"], ["Europe/Madrid", "Atlantic/Canary"]);
+        assert_eq!(countries["DE"], ["Europe/Zurich"]);
+        assert_eq!(countries.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_timespan_units() {
+        // Values which are exact in binary, so that no rounding is involved.
+        assert_eq!(
+            parse_timespan("15625us"),
+            Some(Duration::from_micros(15_625))
+        );
+        assert_eq!(
+            parse_timespan("15625μs"),
+            Some(Duration::from_micros(15_625))
+        );
+        assert_eq!(parse_timespan("125ms"), Some(Duration::from_millis(125)));
+        assert_eq!(parse_timespan("2.5s"), Some(Duration::from_millis(2_500)));
+        assert_eq!(parse_timespan("2min"), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn test_parse_timespan_rejects_malformed_input() {
+        assert_eq!(parse_timespan(""), None);
+        assert_eq!(parse_timespan("ms"), None);
+        assert_eq!(parse_timespan("23"), None);
+        assert_eq!(parse_timespan("23h"), None);
+        assert_eq!(parse_timespan("-5s"), None);
+        assert_eq!(parse_timespan("1.2.3ms"), None);
+        assert_eq!(parse_timespan("1min 30s"), None);
     }
 }
@@ -7,6 +7,8 @@ use cosmic_settings_page as page;
 pub mod date;
 #[cfg(feature = "page-region")]
 pub mod region;
+#[cfg(any(feature = "page-date", feature = "page-region"))]
+mod shared_config;
 
 #[derive(Default)]
 pub struct Page {
@@ -0,0 +1,590 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Static locale data tables used by the region page.
+
+use super::Continent;
+
+/// The most likely locale of each ISO 3166 country.
+pub(super) static COUNTRY_LOCALES: &[(&str, &str)] = &[
+    ("AR", "es_AR"),
+    ("AT", "de_AT"),
+    ("AU", "en_AU"),
+    ("BE", "nl_BE"),
+    ("BG", "bg_BG"),
+    ("BR", "pt_BR"),
+    ("CA", "en_CA"),
+    ("CH", "de_CH"),
+    ("CL", "es_CL"),
+    ("CN", "zh_CN"),
+    ("CO", "es_CO"),
+    ("CZ", "cs_CZ"),
+    ("DE", "de_DE"),
+    ("DK", "da_DK"),
+    ("EE", "et_EE"),
+    ("EG", "ar_EG"),
+    ("ES", "es_ES"),
+    ("FI", "fi_FI"),
+    ("FR", "fr_FR"),
+    ("GB", "en_GB"),
+    ("GR", "el_GR"),
+    ("HK", "zh_HK"),
+    ("HR", "hr_HR"),
+    ("HU", "hu_HU"),
+    ("ID", "id_ID"),
+    ("IE", "en_IE"),
+    ("IL", "he_IL"),
+    ("IN", "hi_IN"),
+    ("IR", "fa_IR"),
+    ("IS", "is_IS"),
+    ("IT", "it_IT"),
+    ("JP", "ja_JP"),
+    ("KR", "ko_KR"),
+    ("LT", "lt_LT"),
+    ("LV", "lv_LV"),
+    ("MX", "es_MX"),
+    ("MY", "ms_MY"),
+    ("NL", "nl_NL"),
+    ("NO", "nb_NO"),
+    ("NZ", "en_NZ"),
+    ("PH", "en_PH"),
+    ("PL", "pl_PL"),
+    ("PT", "pt_PT"),
+    ("RO", "ro_RO"),
+    ("RS", "sr_RS"),
+    ("RU", "ru_RU"),
+    ("SA", "ar_SA"),
+    ("SE", "sv_SE"),
+    ("SI", "sl_SI"),
+    ("SK", "sk_SK"),
+    ("TH", "th_TH"),
+    ("TR", "tr_TR"),
+    ("TW", "zh_TW"),
+    ("UA", "uk_UA"),
+    ("US", "en_US"),
+    ("VN", "vi_VN"),
+    ("ZA", "en_ZA"),
+];
+
+/// The continent of each territory with a glibc locale.
+pub(super) static TERRITORY_CONTINENTS: &[(&str, Continent)] = &[
+    ("AD", Continent::Europe),
+    ("AE", Continent::Asia),
+    ("AF", Continent::Asia),
+    ("AG", Continent::Americas),
+    ("AI", Continent::Americas),
+    ("AL", Continent::Europe),
+    ("AM", Continent::Asia),
+    ("AO", Continent::Africa),
+    ("AR", Continent::Americas),
+    ("AS", Continent::Oceania),
+    ("AT", Continent::Europe),
+    ("AU", Continent::Oceania),
+    ("AW", Continent::Americas),
+    ("AZ", Continent::Asia),
+    ("BA", Continent::Europe),
+    ("BD", Continent::Asia),
+    ("BE", Continent::Europe),
+    ("BF", Continent::Africa),
+    ("BG", Continent::Europe),
+    ("BH", Continent::Asia),
+    ("BI", Continent::Africa),
+    ("BJ", Continent::Africa),
+    ("BN", Continent::Asia),
+    ("BO", Continent::Americas),
+    ("BR", Continent::Americas),
+    ("BS", Continent::Americas),
+    ("BT", Continent::Asia),
+    ("BW", Continent::Africa),
+    ("BY", Continent::Europe),
+    ("BZ", Continent::Americas),
+    ("CA", Continent::Americas),
+    ("CD", Continent::Africa),
+    ("CF", Continent::Africa),
+    ("CG", Continent::Africa),
+    ("CH", Continent::Europe),
+    ("CI", Continent::Africa),
+    ("CL", Continent::Americas),
+    ("CM", Continent::Africa),
+    ("CN", Continent::Asia),
+    ("CO", Continent::Americas),
+    ("CR", Continent::Americas),
+    ("CU", Continent::Americas),
+    ("CV", Continent::Africa),
+    ("CW", Continent::Americas),
+    ("CY", Continent::Europe),
+    ("CZ", Continent::Europe),
+    ("DE", Continent::Europe),
+    ("DJ", Continent::Africa),
+    ("DK", Continent::Europe),
+    ("DM", Continent::Americas),
+    ("DO", Continent::Americas),
+    ("DZ", Continent::Africa),
+    ("EC", Continent::Americas),
+    ("EE", Continent::Europe),
+    ("EG", Continent::Africa),
+    ("ER", Continent::Africa),
+    ("ES", Continent::Europe),
+    ("ET", Continent::Africa),
+    ("FI", Continent::Europe),
+    ("FJ", Continent::Oceania),
+    ("FO", Continent::Europe),
+    ("FR", Continent::Europe),
+    ("GA", Continent::Africa),
+    ("GB", Continent::Europe),
+    ("GD", Continent::Americas),
+    ("GE", Continent::Asia),
+    ("GH", Continent::Africa),
+    ("GL", Continent::Americas),
+    ("GM", Continent::Africa),
+    ("GN", Continent::Africa),
+    ("GQ", Continent::Africa),
+    ("GR", Continent::Europe),
+    ("GT", Continent::Americas),
+    ("GU", Continent::Oceania),
+    ("GW", Continent::Africa),
+    ("GY", Continent::Americas),
+    ("HK", Continent::Asia),
+    ("HN", Continent::Americas),
+    ("HR", Continent::Europe),
+    ("HT", Continent::Americas),
+    ("HU", Continent::Europe),
+    ("ID", Continent::Asia),
+    ("IE", Continent::Europe),
+    ("IL", Continent::Asia),
+    ("IN", Continent::Asia),
+    ("IQ", Continent::Asia),
+    ("IR", Continent::Asia),
+    ("IS", Continent::Europe),
+    ("IT", Continent::Europe),
+    ("JM", Continent::Americas),
+    ("JO", Continent::Asia),
+    ("JP", Continent::Asia),
+    ("KE", Continent::Africa),
+    ("KG", Continent::Asia),
+    ("KH", Continent::Asia),
+    ("KM", Continent::Africa),
+    ("KN", Continent::Americas),
+    ("KP", Continent::Asia),
+    ("KR", Continent::Asia),
+    ("KW", Continent::Asia),
+    ("KZ", Continent::Asia),
+    ("LA", Continent::Asia),
+    ("LB", Continent::Asia),
+    ("LC", Continent::Americas),
+    ("LI", Continent::Europe),
+    ("LK", Continent::Asia),
+    ("LR", Continent::Africa),
+    ("LS", Continent::Africa),
+    ("LT", Continent::Europe),
+    ("LU", Continent::Europe),
+    ("LV", Continent::Europe),
+    ("LY", Continent::Africa),
+    ("MA", Continent::Africa),
+    ("MC", Continent::Europe),
+    ("MD", Continent::Europe),
+    ("ME", Continent::Europe),
+    ("MG", Continent::Africa),
+    ("MK", Continent::Europe),
+    ("ML", Continent::Africa),
+    ("MM", Continent::Asia),
+    ("MN", Continent::Asia),
+    ("MO", Continent::Asia),
+    ("MR", Continent::Africa),
+    ("MT", Continent::Europe),
+    ("MU", Continent::Africa),
+    ("MV", Continent::Asia),
+    ("MW", Continent::Africa),
+    ("MX", Continent::Americas),
+    ("MY", Continent::Asia),
+    ("MZ", Continent::Africa),
+    ("NA", Continent::Africa),
+    ("NE", Continent::Africa),
+    ("NG", Continent::Africa),
+    ("NI", Continent::Americas),
+    ("NL", Continent::Europe),
+    ("NO", Continent::Europe),
+    ("NP", Continent::Asia),
+    ("NR", Continent::Oceania),
+    ("NU", Continent::Oceania),
+    ("NZ", Continent::Oceania),
+    ("OM", Continent::Asia),
+    ("PA", Continent::Americas),
+    ("PE", Continent::Americas),
+    ("PG", Continent::Oceania),
+    ("PH", Continent::Asia),
+    ("PK", Continent::Asia),
+    ("PL", Continent::Europe),
+    ("PR", Continent::Americas),
+    ("PS", Continent::Asia),
+    ("PT", Continent::Europe),
+    ("PW", Continent::Oceania),
+    ("PY", Continent::Americas),
+    ("QA", Continent::Asia),
+    ("RO", Continent::Europe),
+    ("RS", Continent::Europe),
+    ("RU", Continent::Europe),
+    ("RW", Continent::Africa),
+    ("SA", Continent::Asia),
+    ("SB", Continent::Oceania),
+    ("SC", Continent::Africa),
+    ("SD", Continent::Africa),
+    ("SE", Continent::Europe),
+    ("SG", Continent::Asia),
+    ("SI", Continent::Europe),
+    ("SK", Continent::Europe),
+    ("SL", Continent::Africa),
+    ("SM", Continent::Europe),
+    ("SN", Continent::Africa),
+    ("SO", Continent::Africa),
+    ("SR", Continent::Americas),
+    ("SS", Continent::Africa),
+    ("ST", Continent::Africa),
+    ("SV", Continent::Americas),
+    ("SX", Continent::Americas),
+    ("SY", Continent::Asia),
+    ("SZ", Continent::Africa),
+    ("TD", Continent::Africa),
+    ("TG", Continent::Africa),
+    ("TH", Continent::Asia),
+    ("TJ", Continent::Asia),
+    ("TL", Continent::Asia),
+    ("TM", Continent::Asia),
+    ("TN", Continent::Africa),
+    ("TO", Continent::Oceania),
+    ("TR", Continent::Asia),
+    ("TT", Continent::Americas),
+    ("TV", Continent::Oceania),
+    ("TW", Continent::Asia),
+    ("TZ", Continent::Africa),
+    ("UA", Continent::Europe),
+    ("UG", Continent::Africa),
+    ("US", Continent::Americas),
+    ("UY", Continent::Americas),
+    ("UZ", Continent::Asia),
+    ("VA", Continent::Europe),
+    ("VC", Continent::Americas),
+    ("VE", Continent::Americas),
+    ("VN", Continent::Asia),
+    ("VU", Continent::Oceania),
+    ("WS", Continent::Oceania),
+    ("YE", Continent::Asia),
+    ("ZA", Continent::Africa),
+    ("ZM", Continent::Africa),
+    ("ZW", Continent::Africa),
+];
+
+/// The most likely locale of each XKB keyboard layout.
+pub(super) static KEYBOARD_LAYOUT_LOCALES: &[(&str, &str)] = &[
+    ("ara", "ar_EG"),
+    ("be", "fr_BE"),
+    ("bg", "bg_BG"),
+    ("br", "pt_BR"),
+    ("ca", "fr_CA"),
+    ("ch", "de_CH"),
+    ("cn", "zh_CN"),
+    ("cz", "cs_CZ"),
+    ("de", "de_DE"),
+    ("dk", "da_DK"),
+    ("ee", "et_EE"),
+    ("es", "es_ES"),
+    ("fi", "fi_FI"),
+    ("fr", "fr_FR"),
+    ("gb", "en_GB"),
+    ("gr", "el_GR"),
+    ("hr", "hr_HR"),
+    ("hu", "hu_HU"),
+    ("il", "he_IL"),
+    ("in", "hi_IN"),
+    ("ir", "fa_IR"),
+    ("is", "is_IS"),
+    ("it", "it_IT"),
+    ("jp", "ja_JP"),
+    ("kr", "ko_KR"),
+    ("latam", "es_MX"),
+    ("lt", "lt_LT"),
+    ("lv", "lv_LV"),
+    ("nl", "nl_NL"),
+    ("no", "nb_NO"),
+    ("pl", "pl_PL"),
+    ("pt", "pt_PT"),
+    ("ro", "ro_RO"),
+    ("rs", "sr_RS"),
+    ("ru", "ru_RU"),
+    ("se", "sv_SE"),
+    ("si", "sl_SI"),
+    ("sk", "sk_SK"),
+    ("th", "th_TH"),
+    ("tr", "tr_TR"),
+    ("tw", "zh_TW"),
+    ("ua", "uk_UA"),
+    ("us", "en_US"),
+    ("vn", "vi_VN"),
+];
+
+/// Fonts required by language, as their file name prefix and Debian package.
+pub(super) static LOCALE_FONTS: &[(&str, &str, &str)] = &[
+    ("am", "NotoSansEthiopic", "fonts-noto-core"),
+    ("ar", "NotoSansArabic", "fonts-noto-core"),
+    ("bn", "NotoSansBengali", "fonts-noto-core"),
+    ("fa", "NotoSansArabic", "fonts-noto-core"),
+    ("gu", "NotoSansGujarati", "fonts-noto-core"),
+    ("he", "NotoSansHebrew", "fonts-noto-core"),
+    ("hi", "NotoSansDevanagari", "fonts-noto-core"),
+    ("hy", "NotoSansArmenian", "fonts-noto-core"),
+    ("ja", "NotoSansCJK", "fonts-noto-cjk"),
+    ("ka", "NotoSansGeorgian", "fonts-noto-core"),
+    ("km", "NotoSansKhmer", "fonts-noto-core"),
+    ("kn", "NotoSansKannada", "fonts-noto-core"),
+    ("ko", "NotoSansCJK", "fonts-noto-cjk"),
+    ("lo", "NotoSansLao", "fonts-noto-core"),
+    ("ml", "NotoSansMalayalam", "fonts-noto-core"),
+    ("mr", "NotoSansDevanagari", "fonts-noto-core"),
+    ("my", "NotoSansMyanmar", "fonts-noto-core"),
+    ("ne", "NotoSansDevanagari", "fonts-noto-core"),
+    ("pa", "NotoSansGurmukhi", "fonts-noto-core"),
+    ("si", "NotoSansSinhala", "fonts-noto-core"),
+    ("ta", "NotoSansTamil", "fonts-noto-core"),
+    ("te", "NotoSansTelugu", "fonts-noto-core"),
+    ("th", "NotoSansThai", "fonts-noto-core"),
+    ("ur", "NotoNastaliqUrdu", "fonts-noto-core"),
+    ("yi", "NotoSansHebrew", "fonts-noto-core"),
+    ("zh", "NotoSansCJK", "fonts-noto-cjk"),
+];
+
+/// Sample phrases by language, or by locale where a language is written in several scripts.
+pub(super) static SAMPLE_TEXTS: &[(&str, &str)] = &[
+    (
+        "ar",
+        "نص حكيم له سر قاطع وذو شأن عظيم مكتوب على ثوب أخضر ومغلف بجلد أزرق",
+    ),
+    (
+        "be",
+        "У рудога вераб'я ў сховішчы пад фатэлем ляжаць нейкія гаючыя зёлкі.",
+    ),
+    ("bg", "Ах, чудна българска земьо, полюшвай цъфтящи жита."),
+    ("bn", "আমার সোনার বাংলা, আমি তোমায় ভালোবাসি।"),
+    (
+        "ca",
+        "Jove xef, porti whisky amb quinze glaçons d'hidrogen, coi!",
+    ),
+    ("cs", "Příliš žluťoučký kůň úpěl ďábelské ódy."),
+    ("cy", "Parciais fy jac codi baw hud llawn dŵr ger tŷ Mabon."),
+    (
+        "da",
+        "Quizdeltagerne spiste jordbær med fløde, mens cirkusklovnen Walther spillede på xylofon.",
+    ),
+    (
+        "de",
+        "Victor jagt zwölf Boxkämpfer quer über den großen Sylter Deich.",
+    ),
+    ("el", "Ξεσκεπάζω την ψυχοφθόρα βδελυγμία."),
+    ("en", DEFAULT_SAMPLE_TEXT),
+    ("eo", "Eĥoŝanĝo ĉiuĵaŭde."),
+    (
+        "es",
+        "El veloz murciélago hindú comía feliz cardillo y kiwi.",
+    ),
+    (
+        "et",
+        "Põdur Zagrebi tšellomängija-följetonist Ciqo külmetas kehvas garaažis.",
+    ),
+    ("eu", "Kaixo, zer moduz zaude?"),
+    ("fa", "سلام، حال شما چطور است؟"),
+    ("fi", "Törkylempijävongahdus."),
+    ("fr", "Portez ce vieux whisky au juge blond qui fume."),
+    (
+        "ga",
+        "Chuaigh bé mhórshách le dlúthspád fíorfhinn trí hata mo dhea-phorcáin bhig.",
+    ),
+    ("gl", "Ola, como estás?"),
+    ("gu", "નમસ્તે, તમે કેમ છો?"),
+    ("he", "דג סקרן שט בים מאוכזב ולפתע מצא חברה."),
+    ("hi", "नमस्ते, आप कैसे हैं?"),
+    (
+        "hr",
+        "Gojazni đačić s biciklom drži hmelj i finu vatu u džepu nošnje.",
+    ),
+    ("hu", "Árvíztűrő tükörfúrógép."),
+    ("hy", "Բարև, ինչպե՞ս ես։"),
+    (
+        "id",
+        "Muharjo seorang xenofobia universal yang takut pada warga jazirah, contohnya Qatar.",
+    ),
+    ("is", "Kæmi ný öxi hér, ykist þjófum nú bæði víl og ádrepa."),
+    (
+        "it",
+        "Quel vituperabile xenofobo zelante assaggia il whisky ed esclama: alleluja!",
+    ),
+    ("ja", "いろはにほへと ちりぬるを わかよたれそ つねならむ"),
+    ("ka", "გამარჯობა, როგორ ხარ?"),
+    ("kk", "Сәлеметсіз бе, қалыңыз қалай?"),
+    ("km", "សួស្តី តើអ្នកសុខសប្បាយទេ?"),
+    ("kn", "ನಮಸ್ಕಾರ, ಹೇಗಿದ್ದೀರಿ?"),
+    (
+        "ko",
+        "키스의 고유조건은 입술끼리 만나야 하고 특별한 기술은 필요치 않다.",
+    ),
+    (
+        "lt",
+        "Įlinkdama fechtuotojo špaga sublykčiojusi pragręžė apvalų arbūzą.",
+    ),
+    (
+        "lv",
+        "Glāžšķūņa rūķīši dzērumā čiepj Baha koncertflīģeļu vākus.",
+    ),
+    (
+        "mk",
+        "Ѕидарски пејзаж: шугав билмез со чудење џвака ќофте и кељ на туѓ цех.",
+    ),
+    ("ml", "നമസ്കാരം, സുഖമാണോ?"),
+    ("mr", "नमस्कार, तुम्ही कसे आहात?"),
+    ("ms", "Selamat pagi, apa khabar?"),
+    (
+        "nb",
+        "Vår sære Zulu fra badeøya spilte jo whist og quickstep i min taxi.",
+    ),
+    ("nl", "Pa's wijze lynx bezag vroom het fikse aquaduct."),
+    (
+        "nn",
+        "Vår sære Zulu frå badeøya spelte jo whist og quickstep i min taxi.",
+    ),
+    ("pa", "ਸਤ ਸ੍ਰੀ ਅਕਾਲ, ਤੁਸੀਂ ਕਿਵੇਂ ਹੋ?"),
+    ("pl", "Pchnąć w tę łódź jeża lub ośm skrzyń fig."),
+    (
+        "pt",
+        "Luís argüia à Júlia que «brações, fé, chá, óxido, pôr, zângão» eram palavras do português.",
+    ),
+    ("ro", "Fumegând hipnotic sașiul azvârle mreje în bălți."),
+    (
+        "ru",
+        "Съешь же ещё этих мягких французских булок, да выпей чаю.",
+    ),
+    (
+        "sk",
+        "Kŕdeľ šťastných ďatľov učí pri ústí Váhu mĺkveho koňa obhrýzať kôru.",
+    ),
+    ("sl", "Besni dirkač iz formule žuga cehu poštarjev."),
+    ("sq", "Përshëndetje, si jeni?"),
+    (
+        "sr",
+        "Фијуче ветар у шибљу, леди пасаже и куће иза њих и гунђа у оџацима.",
+    ),
+    ("sv", "Flygande bäckasiner söka hwila på mjuka tuvor."),
+    ("ta", "வணக்கம், எப்படி இருக்கிறீர்கள்?"),
+    ("te", "నమస్కారం, మీరు ఎలా ఉన్నారు?"),
+    ("th", "เป็นมนุษย์สุดประเสริฐเลิศคุณค่า"),
+    ("tr", "Pijamalı hasta yağız şoföre çabucak güvendi."),
+    (
+        "uk",
+        "Чуєш їх, доцю, га? Кумедна ж ти, прощайся без ґольфів!",
+    ),
+    ("ur", "السلام علیکم، آپ کیسے ہیں؟"),
+    ("vi", "Tôi có thể ăn thủy tinh mà không hại gì."),
+    ("zh", "我能吞下玻璃而不伤身体。"),
+    ("zh_HK", "我能吞下玻璃而不傷身體。"),
+    ("zh_TW", "我能吞下玻璃而不傷身體。"),
+];
+
+/// Bidirectional sample sentences by right-to-left language.
+pub(super) static BIDI_SAMPLE_TEXTS: &[(&str, &str)] = &[
+    ("ar", "يعمل نظام COSMIC باللغة العربية."),
+    ("fa", "سیستم COSMIC به زبان فارسی کار می‌کند."),
+    ("he", "מערכת COSMIC פועלת בעברית."),
+    ("ur", "COSMIC نظام اردو میں کام کرتا ہے۔"),
+    ("yi", "די COSMIC סיסטעם אַרבעט אויף ייִדיש."),
+];
+
+/// Postal address formats by territory, derived from the `postal_fmt` field of
+/// glibc's `LC_ADDRESS` locale category.
+pub(super) static POSTAL_FORMATS: &[(&str, &str)] = &[
+    ("AT", "%f%N%a%N%d%N%b%N%s %h%t%e%t%r%N%z %T%N%c%N"),
+    ("AU", "%f%N%a%N%d%N%b%N%h %s %e %r%N%T %S %z%N%c%N"),
+    ("BR", "%a%N%f%N%d%N%b%N%s %h, %e %r%N%z %T%N%S%N%c%N"),
+    ("CA", "%a%N%f%N%d%N%b%N%h %s %e %r%N%T %S %z%N%c%N"),
+    ("CH", "%f%N%a%N%d%N%b%N%s %h%t%e%t%r%N%z %T%N%c%N"),
+    ("CN", "%c%N%T%N%s %h %e %r%N%b%N%z %f%N%a%N"),
+    ("DE", "%f%N%a%N%d%N%b%N%s %h%t%e%t%r%N%z %T%N%c%N"),
+    ("ES", "%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N"),
+    ("FR", "%f%N%a%N%d%N%b%N%h %s %e %r%N%z %T%N%c%N"),
+    ("GB", "%f%N%a%N%d%N%b%N%h %s %e %r%N%T%N%z%N%c%N"),
+    ("IE", "%f%N%a%N%d%N%b%N%h %s %e %r%N%T%N%S%N%z%N%c%N"),
+    ("IN", "%f%N%a%N%d%N%b%N%h %s %e %r%N%T %z%N%S%N%c%N"),
+    ("IT", "%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T %S%N%c%N"),
+    ("JP", "%z%N%S%T%s%h%e%r%N%b%N%d%N%f%N%a%N%c%N"),
+    ("KR", "%c%N%S %T %s %h %e %r%N%b%N%d%N%f%N%a%N%z%N"),
+    ("MX", "%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T, %S%N%c%N"),
+    ("NL", "%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N"),
+    ("PL", "%f%N%a%N%d%N%b%N%s %h%t%e%t%r%N%z %T%N%c%N"),
+    ("RU", "%f%N%a%N%d%N%b%N%s %h %e %r%N%T%N%S%N%z%N%c%N"),
+    ("SE", "%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N"),
+    ("TW", "%c%N%z%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N"),
+    ("US", "%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N"),
+];
+
+/// The calendar systems of regions which use more than the Gregorian calendar, in order of
+/// preference, from the calendar preferences of CLDR.
+pub(super) const REGIONAL_CALENDARS: &[(&[&str], &[&str])] = &[
+    (
+        &[
+            "BD", "DJ", "DZ", "EH", "ER", "ID", "IQ", "JO", "KM", "LB", "LY", "MA", "MR", "MY",
+            "NE", "OM", "PK", "PS", "SD", "SY", "TD", "TN", "YE",
+        ],
+        &["gregorian", "islamic", "islamic-civil", "islamic-tbla"],
+    ),
+    (
+        &["AL", "AZ", "MV", "TJ", "TM", "TR", "UZ", "XK"],
+        &["gregorian", "islamic-civil", "islamic-tbla"],
+    ),
+    (
+        &["AE", "BH", "KW", "QA"],
+        &[
+            "gregorian",
+            "islamic-umalqura",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    (
+        &["AF", "IR"],
+        &[
+            "persian",
+            "gregorian",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    (&["CN", "CX", "HK", "MO", "SG"], &["gregorian", "chinese"]),
+    (
+        &["EG"],
+        &[
+            "gregorian",
+            "coptic",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    (&["ET"], &["gregorian", "ethiopic"]),
+    (
+        &["IL"],
+        &[
+            "gregorian",
+            "hebrew",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    (&["IN"], &["gregorian", "indian"]),
+    (&["JP"], &["gregorian", "japanese"]),
+    (&["KR"], &["gregorian", "dangi"]),
+    (
+        &["SA"],
+        &["islamic-umalqura", "gregorian", "islamic", "islamic-rgsa"],
+    ),
+    (&["TH"], &["buddhist", "gregorian"]),
+    (&["TW"], &["gregorian", "roc", "chinese"]),
+];
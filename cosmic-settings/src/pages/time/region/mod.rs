@@ -0,0 +1,7478 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::utils::format_size;
+use crate::widget::selection_context_item_with;
+use cosmic::app::{ContextDrawer, context_drawer};
+use cosmic::iced::{Alignment, Length, Subscription};
+use cosmic::widget::{self, button};
+use cosmic::{Apply, Element};
+use cosmic_config::{ConfigGet, ConfigSet};
+use cosmic_settings_page::{self as page, Section, section};
+use eyre::Context;
+use futures::{SinkExt, StreamExt};
+use i18n_embed::LanguageLoader;
+use icu::calendar::preferences::CalendarPreferences;
+use icu::calendar::types::Weekday;
+use icu::calendar::week::{WeekInformation, WeekPreferences};
+use icu::calendar::{AnyCalendarKind, Gregorian};
+use icu::collator::options::CollatorOptions;
+use icu::collator::{Collator, CollatorBorrowed, CollatorPreferences};
+use icu::datetime::fieldsets::builder::{DateFields, FieldSetBuilder};
+use icu::datetime::fieldsets::enums::DateFieldSet;
+use icu::datetime::input::{Date, DateTime, Time};
+use icu::datetime::options::{self as datetime_options, TimePrecision, YearStyle};
+use icu::datetime::{
+    DateTimeFormatter, DateTimeFormatterPreferences, NoCalendarFormatter, fieldsets,
+};
+use icu::decimal::DecimalFormatter;
+use icu::decimal::input::{Decimal, SignDisplay};
+use icu::list::ListFormatter;
+use icu::list::options::ListFormatterOptions;
+use icu::locale::preferences::extensions::unicode::keywords::{CalendarAlgorithm, HourCycle};
+use icu::locale::subtags::{Language, Script};
+use icu::locale::{Direction, Locale, LocaleCanonicalizer, LocaleDirectionality, LocaleExpander};
+use icu::plurals::{PluralCategory, PluralRules, PluralRulesPreferences};
+use icu::time::zone::IanaParser;
+use icu_experimental::compactdecimal::CompactDecimalFormatter;
+use icu_experimental::dimension::currency::CurrencyCode;
+use icu_experimental::dimension::currency::formatter::CurrencyFormatter;
+use icu_experimental::dimension::percent::formatter::PercentFormatter;
+use icu_experimental::displaynames::{
+    DisplayNamesOptions, DisplayNamesPreferences, LanguageDisplayNames,
+};
+use icu_experimental::duration::options::{BaseStyle, DurationFormatterOptions};
+use icu_experimental::duration::{
+    self as icu_duration, DurationFormatter, ValidatedDurationFormatterOptions,
+};
+use locales_rs as locale;
+use slotmap::{DefaultKey, SlotMap};
+
+mod data;
+
+use data::{
+    BIDI_SAMPLE_TEXTS, COUNTRY_LOCALES, KEYBOARD_LAYOUT_LOCALES, LOCALE_FONTS, POSTAL_FORMATS,
+    REGIONAL_CALENDARS, SAMPLE_TEXTS, TERRITORY_CONTINENTS,
+};
+
+static GNOME_LANGUAGE_SELECTOR: &str = "gnome-language-selector";
+static APT_GET: &str = "apt-get";
+static APT_CACHE: &str = "apt-cache";
+
+/// Held while a language pack is installed, since apt-get can only run once at a time.
+static INSTALL_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+static SEARCH_HISTORY_KEY: &str = "language_search_history";
+/// Whether the location has already been used to suggest a language.
+static LOCATION_SUGGESTION_KEY: &str = "location_language_suggested";
+static GEOCLUE: &str = "org.freedesktop.GeoClue2";
+/// How long to wait for GeoClue to report a location.
+const GEOCLUE_TIMEOUT: Duration = Duration::from_secs(30);
+static LOCALE_HISTORY_KEY: &str = "locale_change_history";
+const LOCALE_HISTORY_LIMIT: usize = 20;
+/// Whether locale changes are also applied to the display manager's login screen.
+static LOGIN_SCREEN_KEY: &str = "apply_to_login_screen";
+/// Whether adding a language also selects it as the region.
+static AUTO_SELECT_REGION_KEY: &str = "auto_select_region";
+/// Whether the notice about changes to the sort order of files has been shown.
+static SORT_ORDER_NOTICE_KEY: &str = "sort_order_change_noticed";
+
+/// Config key for the font scale of the formatting previews.
+static PREVIEW_SCALE_KEY: &str = "locale_preview_font_scale";
+/// Config key of the ICU skeleton which overrides the locale's date format in the preview.
+static DATE_SKELETON_KEY: &str = "locale_custom_date_skeleton";
+static DATE_SKELETON_DOCS: &str =
+    "https://unicode-org.github.io/icu/userguide/format_parse/datetime/#date-field-symbol-table";
+
+/// The font size of the formatting previews at a scale of `1.0`.
+const BASE_PREVIEW_SIZE: f32 = 14.0;
+const SEARCH_HISTORY_LIMIT: usize = 10;
+
+/// The only language kept when restoring the defaults, if the distribution declares none.
+static CLEAR_LANGUAGES_FALLBACK: &str = "en_US.UTF-8";
+
+/// Files which hold the locale applied at boot, in order of preference.
+static PERSISTED_LOCALE_FILES: &[&str] = &["/etc/locale.conf", "/etc/default/locale"];
+
+/// Locale categories which fall back to `LANG` when they are not set.
+static LOCALE_CATEGORIES: &[&str] = &[
+    "LC_ADDRESS",
+    "LC_IDENTIFICATION",
+    "LC_MEASUREMENT",
+    "LC_MONETARY",
+    "LC_NAME",
+    "LC_NUMERIC",
+    "LC_PAPER",
+    "LC_TELEPHONE",
+    "LC_TIME",
+];
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    AddLanguage(DefaultKey),
+    AddLanguageContext,
+    AddLanguageSearch(String),
+    AppLocaleSupportContext,
+    ApplyLocaleFile,
+    ApplySystemWide,
+    CancelClearAllLanguages,
+    CancelImportLocale,
+    CancelLocaleFile,
+    CancelRemoveLcAll,
+    ClearAllLanguages,
+    ClearAllLanguagesDialog,
+    ClearSearchHistory,
+    ConsolidateLocaleFiles,
+    ContinentFilter(Continent),
+    ScriptFilter(LocaleScript),
+    WritingDirectionFilter(WritingDirection),
+    CopyDockerEnv,
+    BrokenLocales(Vec<String>),
+    CopyFormattedPreview,
+    DismissLanguageSuggestion,
+    DownloadLanguagePack(String),
+    DismissKeyboardLayoutMismatch,
+    DismissLocaleFilesConflict,
+    DismissPersistNotice,
+    DismissInputMethodHint,
+    DismissReloginNotice,
+    DismissSortOrderNotice,
+    ExpandLanguagePopover(Option<usize>),
+    #[cfg(feature = "xdg-portal")]
+    ExportAnsibleTask,
+    SetPreviewScale(f32),
+    ImportLocaleFromUser(String),
+    InstallAdditionalLanguages,
+    InstallFonts,
+    InstallInputMethod,
+    InstallProgress {
+        lang_code: String,
+        state: InstallState,
+    },
+    LanguageHovered(DefaultKey, bool),
+    LanguagePackSizes(HashMap<String, u64>),
+    LocaleConsistency(LocaleConsistency),
+    LocaleFileLoaded(PathBuf, BTreeMap<String, String>),
+    LocationSuggestion(Option<String>),
+    LoginScreenLocaleResult(bool, Result<(), String>),
+    MissingFonts(Vec<String>),
+    OtherUsers(Vec<UserInfo>),
+    OpenUrl(String),
+    SelectImportUser(usize),
+    SelectRegion(DefaultKey),
+    SourceContext(SourceContext),
+    Refresh(Arc<eyre::Result<PageRefresh>>),
+    RegionContext,
+    RemoveLanguage(DefaultKey),
+    RemoveLcAll,
+    RemoveLcAllDialog,
+    RemoveSearchHistory(usize),
+    RestoreLocaleChange(usize),
+    SaveDateSkeleton,
+    SetAutoSelectRegion(bool),
+    SetCalendarSystem(String),
+    SetDateSkeleton(String),
+    SetLocaleFromEnvFile(PathBuf),
+    SetLoginScreenLocale(bool),
+    SetTextDirection(TextDirection),
+    ShellLocaleOverrides(Vec<ShellLocaleOverride>),
+    Tick,
+    ToggleChangeHistory,
+    ToggleLanguageOrderInfo,
+    ToggleLivePreview(bool),
+    ToggleLookupChain,
+    ToggleShellLocaleOverrides,
+    TranslationCatalogs(Arc<BTreeMap<String, Vec<String>>>),
+    UseSystemLocale,
+    UseUserLocale,
+    UserLocale(String, Option<String>),
+}
+
+impl From<Message> for crate::app::Message {
+    fn from(message: Message) -> Self {
+        crate::pages::Message::Region(message).into()
+    }
+}
+
+impl From<Message> for crate::pages::Message {
+    fn from(message: Message) -> Self {
+        crate::pages::Message::Region(message)
+    }
+}
+
+/// The state of a language pack installation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstallState {
+    /// Waiting for another installation to finish.
+    Pending,
+    /// Installing, with its progress as a percentage.
+    InProgress(u8),
+    Done,
+    Failed(String),
+}
+
+/// Whether the system locale agrees with the language of the current user's account.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum LocaleConsistency {
+    #[default]
+    Consistent,
+    /// The locale1 system locale and the AccountsService user language differ.
+    Diverged { system: String, user: String },
+}
+
+/// An input method which is needed to type a language.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMethodInfo {
+    /// The IBus engine, such as `ibus-mozc`.
+    pub engine: String,
+    /// The Debian package which provides the engine.
+    pub package: String,
+    /// The project page of the engine.
+    pub url: String,
+}
+
+/// A locale variable set by a shell startup file, which overrides locale1 in interactive shells.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShellLocaleOverride {
+    pub file: PathBuf,
+    /// The line of the assignment, counting from 1.
+    pub line: usize,
+    pub variable: String,
+    pub value: String,
+}
+
+/// Whether a gettext translation catalog covers a locale. One application may ship several
+/// catalogs, and some catalogs belong to libraries rather than applications.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppLocaleSupportInfo {
+    /// The gettext domain of the catalog.
+    pub app_name: String,
+    pub supported: bool,
+}
+
+/// Another user account, whose locale settings can be copied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserInfo {
+    pub username: String,
+    pub real_name: String,
+}
+
+/// A change of the system locale, kept so that it can be restored later.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct LocaleChange {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub old_lang: String,
+    pub new_lang: String,
+    pub new_region: String,
+}
+
+/// A display manager whose login screen locale can be configured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplayManager {
+    Gdm,
+    Sddm,
+}
+
+/// What a language suggestion was inferred from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuggestionSource {
+    KeyboardLayout,
+    Location,
+}
+
+/// A continent by which the add language drawer can be filtered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Continent {
+    Africa,
+    Americas,
+    Asia,
+    Europe,
+    Oceania,
+}
+
+impl Continent {
+    const ALL: [Self; 5] = [
+        Self::Africa,
+        Self::Americas,
+        Self::Asia,
+        Self::Europe,
+        Self::Oceania,
+    ];
+
+    fn label(self) -> String {
+        match self {
+            Self::Africa => fl!("continent", "africa"),
+            Self::Americas => fl!("continent", "americas"),
+            Self::Asia => fl!("continent", "asia"),
+            Self::Europe => fl!("continent", "europe"),
+            Self::Oceania => fl!("continent", "oceania"),
+        }
+    }
+}
+
+/// A writing system by which the add language drawer can be filtered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocaleScript {
+    Latin,
+    Cyrillic,
+    Arabic,
+    /// Chinese, Japanese and Korean scripts.
+    Cjk,
+    Devanagari,
+    Greek,
+    Hebrew,
+    Thai,
+    Other,
+}
+
+impl LocaleScript {
+    const FILTERS: [Self; 8] = [
+        Self::Latin,
+        Self::Cyrillic,
+        Self::Arabic,
+        Self::Cjk,
+        Self::Devanagari,
+        Self::Greek,
+        Self::Hebrew,
+        Self::Thai,
+    ];
+
+    fn label(self) -> String {
+        match self {
+            Self::Latin => fl!("script", "latin"),
+            Self::Cyrillic => fl!("script", "cyrillic"),
+            Self::Arabic => fl!("script", "arabic"),
+            Self::Cjk => fl!("script", "cjk"),
+            Self::Devanagari => fl!("script", "devanagari"),
+            Self::Greek => fl!("script", "greek"),
+            Self::Hebrew => fl!("script", "hebrew"),
+            Self::Thai => fl!("script", "thai"),
+            Self::Other => fl!("script", "other"),
+        }
+    }
+}
+
+/// The direction in which a script is written, by which the add language drawer can be filtered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WritingDirection {
+    LeftToRight,
+    RightToLeft,
+    /// Scripts which are also written in vertical lines, such as Chinese and Japanese.
+    /// Their locales match this as well as left to right.
+    TopToBottom,
+}
+
+impl WritingDirection {
+    const ALL: [Self; 3] = [Self::LeftToRight, Self::RightToLeft, Self::TopToBottom];
+
+    fn label(self) -> String {
+        match self {
+            Self::LeftToRight => fl!("writing-direction", "ltr"),
+            Self::RightToLeft => fl!("writing-direction", "rtl"),
+            Self::TopToBottom => fl!("writing-direction", "ttb"),
+        }
+    }
+}
+
+/// The direction in which text of the primary language is laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// The order in which the components of a numeric date are written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+impl DateOrder {
+    fn label(self) -> String {
+        match self {
+            Self::DayMonthYear => fl!("formatting", "day-month-year"),
+            Self::MonthDayYear => fl!("formatting", "month-day-year"),
+            Self::YearMonthDay => fl!("formatting", "year-month-day"),
+        }
+    }
+}
+
+enum ContextView {
+    AddLanguage,
+    AppLocaleSupport,
+    Region,
+}
+
+#[derive(Clone, Debug)]
+pub enum SourceContext {
+    /// Replaces a deprecated language subtag with its canonical form.
+    FixDeprecated(usize),
+    Remove(usize),
+    /// Moves a language from the first position to the second one.
+    SetPosition(usize, usize),
+}
+
+/// A row of the formatting previews, which the copy button also shares as `label: value`.
+#[derive(Clone, Debug)]
+struct PreviewRow {
+    label: String,
+    value: String,
+    /// A caption after the value, such as the regional date order.
+    note: Option<String>,
+    style: PreviewStyle,
+}
+
+impl PreviewRow {
+    fn new(label: String, value: String) -> Self {
+        Self {
+            label,
+            value,
+            note: None,
+            style: PreviewStyle::Bold,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PreviewStyle {
+    Bold,
+    Caption,
+    /// Glyphs shown in the regular font, whose fallback fonts would be hidden by bold text.
+    Regular,
+}
+
+/// Date and time formatters of the formatting previews, built on refresh so that each tick
+/// of the live preview only formats the current time.
+struct PreviewFormatters {
+    /// The formatter of the custom date skeleton, if one is set.
+    custom_date: Option<DateTimeFormatter<DateFieldSet>>,
+    date: DateTimeFormatter<fieldsets::YMD>,
+    short_date: DateTimeFormatter<fieldsets::YMD>,
+    full_date: DateTimeFormatter<fieldsets::YMDE>,
+    date_time: DateTimeFormatter<fieldsets::YMDT>,
+    time: DateTimeFormatter<fieldsets::T>,
+    /// The order of the date components, such as `(DD/MM/YYYY)`.
+    date_order: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SystemLocale {
+    lang_code: String,
+    display_name: String,
+    region_name: String,
+}
+
+impl Eq for SystemLocale {}
+
+impl Ord for SystemLocale {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.display_name.cmp(&other.display_name)
+    }
+}
+
+impl PartialOrd for SystemLocale {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SystemLocale {
+    fn eq(&self, other: &Self) -> bool {
+        self.display_name == other.display_name
+    }
+}
+
+#[derive(Debug)]
+pub struct PageRefresh {
+    config: Option<(cosmic_config::Config, Vec<String>)>,
+    registry: Registry,
+    language: Option<SystemLocale>,
+    region: Option<SystemLocale>,
+    available_languages: SlotMap<DefaultKey, SystemLocale>,
+    system_locales: BTreeMap<String, SystemLocale>,
+    language_selector_available: bool,
+    language_packs_available: bool,
+    display_manager: Option<DisplayManager>,
+    keyboard_layout: Option<String>,
+    suggested_language: Option<DefaultKey>,
+    languages_configured: bool,
+    locale_not_persisted: bool,
+    conflicting_locale_files: Vec<(PathBuf, BTreeMap<String, String>)>,
+    distribution_locale: Option<String>,
+    monetary_currency: Option<CurrencyCode>,
+}
+
+#[derive(Default)]
+pub struct Page {
+    entity: page::Entity,
+    config: Option<(cosmic_config::Config, Vec<String>)>,
+    context: Option<ContextView>,
+    language: Option<SystemLocale>,
+    region: Option<SystemLocale>,
+    available_languages: SlotMap<DefaultKey, SystemLocale>,
+    system_locales: BTreeMap<String, SystemLocale>,
+    registry: Option<locale::Registry>,
+    expanded_source_popover: Option<usize>,
+    add_language_search: String,
+    /// Recent language searches which produced results, most recent first.
+    search_history: Vec<String>,
+    /// The active display manager, if its login screen locale can be configured.
+    display_manager: Option<DisplayManager>,
+    set_login_locale: bool,
+    auto_select_region: bool,
+    /// The default X11 keyboard layout, such as `us`.
+    keyboard_layout: Option<String>,
+    keyboard_layout_mismatch_dismissed: bool,
+    /// Recent locale changes, newest first.
+    locale_change_history: Vec<LocaleChange>,
+    change_history_expanded: bool,
+    language_order_info_expanded: bool,
+    lookup_chain_expanded: bool,
+    /// The language under the cursor in the add language drawer.
+    hovered_language: Option<DefaultKey>,
+    /// The font scale of the formatting previews, from 0.8 to 2.0.
+    preview_scale: Option<f32>,
+    /// An ICU date skeleton, such as `yMMMMd`, which overrides the locale's date format.
+    custom_date_skeleton: Option<String>,
+    /// The continent which the add language drawer is filtered to.
+    continent_filter: Option<Continent>,
+    /// The script which the add language drawer is filtered to.
+    script_filter: Option<LocaleScript>,
+    writing_direction_filter: Option<WritingDirection>,
+    /// Whether gnome-language-selector is in the path.
+    language_selector_available: bool,
+    /// Whether language packs can be installed with apt-get.
+    language_packs_available: bool,
+    /// Language pack installations started from this page, by locale.
+    installing_locales: HashMap<String, InstallState>,
+    /// Installed size in bytes of the language pack of each preferred language.
+    language_pack_sizes: HashMap<String, u64>,
+    /// Cached LC_NUMERIC locale in icu locale format.
+    numeric_locale: Option<Locale>,
+    /// The currency of the LC_MONETARY locale, as defined by its glibc locale data.
+    monetary_currency: Option<CurrencyCode>,
+    /// Cached LC_TIME locale in icu locale format.
+    time_locale: Option<Locale>,
+    icu_unsupported_locale: Option<String>,
+    /// Language suggested from the keyboard layout or location when none are configured.
+    suggested_language: Option<(DefaultKey, SuggestionSource)>,
+    /// Whether the preferred languages have ever been saved.
+    languages_configured: bool,
+    /// Whether the location has been looked up for a language suggestion in this session.
+    location_requested: bool,
+    /// Configured locales which are no longer installed on the system.
+    broken_locales: HashSet<String>,
+    /// Font packages which the preferred languages need but which are not installed.
+    missing_fonts: Vec<String>,
+    /// Whether the primary language is written right-to-left.
+    is_rtl: bool,
+    /// Whether the system locale differs from the locale applied at boot.
+    locale_not_persisted: bool,
+    /// Locale config files which disagree with each other.
+    conflicting_locale_files: Vec<(PathBuf, BTreeMap<String, String>)>,
+    /// Locale variables read from a file, awaiting confirmation before they are applied.
+    pending_locale_file: Option<(PathBuf, BTreeMap<String, String>)>,
+    /// Whether restoring the default language is awaiting confirmation.
+    clear_languages_dialog: bool,
+    /// The default locale which the distribution declares in `/etc/os-release`.
+    distribution_locale: Option<String>,
+    /// Whether removing `LC_ALL` is awaiting confirmation.
+    remove_lc_all_dialog: bool,
+    /// Whether running applications keep the previous language until the next login.
+    relogin_required: bool,
+    /// Whether the region change alters how file managers sort files.
+    sort_order_changed: bool,
+    locale_consistency: LocaleConsistency,
+    /// An input method recommended for the most recently added language, with its name.
+    input_method_hint: Option<(String, InputMethodInfo)>,
+    /// Locale variables set by the user's shell startup files.
+    shell_locale_overrides: Vec<ShellLocaleOverride>,
+    shell_locale_overrides_expanded: bool,
+    /// Other user accounts, and their names as shown in the copy from user dropdown.
+    other_users: Vec<UserInfo>,
+    other_user_names: Vec<String>,
+    /// The user whose language is awaiting confirmation before it is copied, with the language.
+    import_locale: Option<(String, String)>,
+    /// The gettext catalogs with the locale directories holding them, scanned once.
+    translation_catalogs: Option<Arc<BTreeMap<String, Vec<String>>>>,
+    /// Which translation catalogs cover the primary language, with that language.
+    app_locale_support: Option<(String, Vec<AppLocaleSupportInfo>)>,
+    /// Whether the formatting previews show the current time instead of the reference date.
+    live_preview: bool,
+    /// The local time of the latest tick of the live preview.
+    live_preview_now: Option<DateTime<Gregorian>>,
+    /// The abbreviation of the system timezone at the latest tick, such as `EDT`.
+    timezone_abbreviation: String,
+    preview_formatters: Option<PreviewFormatters>,
+    /// Formatting previews which do not depend on the previewed date and time.
+    preview_rows: Vec<PreviewRow>,
+    /// CLDR identifiers of the calendar systems of the time locale, beginning with its default.
+    calendar_systems: Vec<&'static str>,
+    calendar_system_names: Vec<String>,
+    /// The calendar system in which dates are previewed, if not the locale's default.
+    calendar_system: Option<String>,
+}
+
+impl page::Page<crate::pages::Message> for Page {
+    fn set_id(&mut self, entity: page::Entity) {
+        self.entity = entity;
+    }
+
+    fn content(
+        &self,
+        sections: &mut SlotMap<section::Entity, Section<crate::pages::Message>>,
+    ) -> Option<page::Content> {
+        Some(vec![
+            sections.insert(preferred_languages::section()),
+            sections.insert(formatting::section()),
+            sections.insert(change_history::section()),
+        ])
+    }
+
+    fn info(&self) -> page::Info {
+        page::Info::new("time-region", "preferences-region-and-language-symbolic")
+            .title(fl!("time-region"))
+            .description(fl!("xdg-entry-region-language-comment"))
+    }
+
+    fn badge(&self) -> Option<u32> {
+        self.preferred_language_count_badge()
+    }
+
+    fn header_view(&self) -> Option<Element<'_, crate::pages::Message>> {
+        if self.system_locales.is_empty() {
+            return None;
+        }
+
+        let score = self.locale_completeness_score();
+
+        let counts = widget::text::caption(fl!(
+            "language-counts",
+            configured = self.configured_languages_count(),
+            available = self.total_installed_languages_count()
+        ));
+
+        let content = widget::row::with_capacity(3)
+            .push(counts)
+            .push(
+                cosmic::iced::widget::progress_bar(0.0..=100.0, f32::from(score))
+                    .length(Length::Fixed(80.0))
+                    .girth(Length::Fixed(6.0)),
+            )
+            .push(
+                widget::button::text(fl!("locale-completeness", percent = score))
+                    .on_press(Message::RegionContext),
+            )
+            .spacing(cosmic::theme::spacing().space_xxs)
+            .align_y(Alignment::Center)
+            .apply(widget::container)
+            .width(Length::Fill)
+            .align_x(Alignment::End)
+            .apply(Element::from)
+            .map(crate::pages::Message::Region);
+
+        Some(content)
+    }
+
+    fn on_enter(&mut self) -> cosmic::Task<crate::pages::Message> {
+        cosmic::task::future(async move { Message::Refresh(Arc::new(page_reload().await)) })
+    }
+
+    fn subscription(&self, _core: &cosmic::Core) -> Subscription<crate::pages::Message> {
+        let watch = Subscription::run(watch_locale_config_files);
+
+        if !self.live_preview {
+            return watch.map(crate::pages::Message::Region);
+        }
+
+        Subscription::batch([
+            watch,
+            cosmic::iced::time::every(Duration::from_secs(1)).map(|_| Message::Tick),
+        ])
+        .map(crate::pages::Message::Region)
+    }
+
+    fn on_leave(&mut self) -> cosmic::Task<crate::pages::Message> {
+        self.add_language_search = String::new();
+        self.available_languages = SlotMap::new();
+        self.broken_locales = HashSet::new();
+        self.clear_languages_dialog = false;
+        self.remove_lc_all_dialog = false;
+        self.config = None;
+        self.conflicting_locale_files = Vec::new();
+        self.context = None;
+        self.expanded_source_popover = None;
+        self.language = None;
+        self.locale_not_persisted = false;
+        self.pending_locale_file = None;
+        self.region = None;
+        self.registry = None;
+        self.suggested_language = None;
+        self.system_locales = BTreeMap::new();
+        cosmic::Task::none()
+    }
+
+    fn dialog(&self) -> Option<Element<'_, crate::pages::Message>> {
+        if self.clear_languages_dialog {
+            return Some(self.clear_all_languages_dialog());
+        }
+
+        if self.remove_lc_all_dialog {
+            return Some(self.remove_lc_all_dialog());
+        }
+
+        if self.import_locale.is_some() {
+            return self.import_locale_dialog();
+        }
+
+        let (path, variables) = self.pending_locale_file.as_ref()?;
+
+        let variables = variables.iter().fold(
+            widget::column::with_capacity(variables.len()),
+            |column, (var, value)| {
+                column.push(widget::text::body(
+                    [var.as_str(), "=", value.as_str()].concat(),
+                ))
+            },
+        );
+
+        let primary_action =
+            widget::button::suggested(fl!("apply-system-wide")).on_press(Message::ApplyLocaleFile);
+
+        let secondary_action =
+            widget::button::standard(fl!("cancel")).on_press(Message::CancelLocaleFile);
+
+        widget::dialog()
+            .title(fl!("locale-file-dialog", path = path.display().to_string()))
+            .body(fl!("locale-file-dialog", "description"))
+            .control(variables)
+            .primary_action(primary_action)
+            .secondary_action(secondary_action)
+            .apply(Element::from)
+            .map(crate::pages::Message::Region)
+            .apply(Some)
+    }
+
+    fn context_drawer(&self) -> Option<ContextDrawer<'_, crate::pages::Message>> {
+        Some(match self.context.as_ref()? {
+            ContextView::AddLanguage => {
+                let search = widget::column::with_capacity(2)
+                    .push(
+                        widget::search_input("", &self.add_language_search)
+                            .on_input(Message::AddLanguageSearch)
+                            .on_clear(Message::AddLanguageSearch(String::new())),
+                    )
+                    .push(self.realtime_search_result_count())
+                    .spacing(cosmic::theme::spacing().space_xxs)
+                    .apply(Element::from)
+                    .map(crate::pages::Message::from);
+                let drawer = context_drawer(
+                    self.add_language_view().map(crate::pages::Message::from),
+                    crate::pages::Message::CloseContextDrawer,
+                )
+                .title(fl!("add-language", "context"))
+                .header(search);
+
+                let clear_history_button = (!self.search_history.is_empty()).then(|| {
+                    widget::button::standard(fl!("clear-search-history"))
+                        .on_press(Message::ClearSearchHistory)
+                });
+
+                let install_additional_button = self.language_selector_available.then(|| {
+                    widget::button::standard(fl!("install-additional-languages"))
+                        .on_press(Message::InstallAdditionalLanguages)
+                });
+
+                if clear_history_button.is_some() || install_additional_button.is_some() {
+                    let footer = widget::row::with_capacity(3)
+                        .push_maybe(clear_history_button)
+                        .push(widget::space::horizontal())
+                        .push_maybe(install_additional_button)
+                        .spacing(cosmic::theme::spacing().space_xs)
+                        .apply(Element::from)
+                        .map(crate::pages::Message::from);
+
+                    drawer.footer(footer)
+                } else {
+                    drawer
+                }
+            }
+            ContextView::AppLocaleSupport => context_drawer(
+                self.app_locale_support_view()
+                    .map(crate::pages::Message::from),
+                crate::pages::Message::CloseContextDrawer,
+            )
+            .title(fl!("app-locale-support", "context")),
+            ContextView::Region => {
+                let search = widget::search_input("", &self.add_language_search)
+                    .on_input(Message::AddLanguageSearch)
+                    .on_clear(Message::AddLanguageSearch(String::new()))
+                    .apply(Element::from)
+                    .map(crate::pages::Message::from);
+
+                let read_locale_conf_button = widget::button::standard(fl!("read-locale-conf"))
+                    .on_press(Message::SetLocaleFromEnvFile(PathBuf::from(
+                        PERSISTED_LOCALE_FILES[0],
+                    )))
+                    .apply(widget::container)
+                    .width(Length::Fill)
+                    .align_x(Alignment::End)
+                    .apply(Element::from)
+                    .map(crate::pages::Message::from);
+
+                context_drawer(
+                    self.region_view().map(crate::pages::Message::from),
+                    crate::pages::Message::CloseContextDrawer,
+                )
+                .title(fl!("region"))
+                .header(search)
+                .footer(read_locale_conf_button)
+            }
+        })
+    }
+}
+
+impl Page {
+    pub fn update(&mut self, message: Message) -> cosmic::Task<crate::app::Message> {
+        match message {
+            Message::AddLanguage(id) => {
+                self.suggested_language = None;
+
+                if let Some(language) = self.available_languages.get(id)
+                    && let Some((config, locales)) = self.config.as_mut()
+                    && !locales.contains(&language.lang_code)
+                {
+                    locales.push(language.lang_code.clone());
+                    _ = config.set("system_locales", &locales);
+
+                    self.input_method_hint = recommended_input_method(&language.lang_code)
+                        .map(|info| (language.display_name.clone(), info));
+
+                    let lang_code = language.lang_code.clone();
+                    let fonts = cosmic::task::future(async move {
+                        Message::MissingFonts(required_fonts_for_locale(&lang_code).await)
+                    });
+
+                    return cosmic::Task::batch([fonts, self.auto_set_region_on_language_add(id)]);
+                }
+            }
+
+            Message::MissingFonts(packages) => {
+                for package in packages {
+                    if !self.missing_fonts.contains(&package) {
+                        self.missing_fonts.push(package);
+                    }
+                }
+            }
+
+            Message::InstallFonts => {
+                let packages = std::mem::take(&mut self.missing_fonts);
+                let locales = self
+                    .config
+                    .as_ref()
+                    .map(|(_, locales)| locales.clone())
+                    .unwrap_or_default();
+
+                return cosmic::task::future(async move {
+                    if let Err(why) = install_font_packages(&packages).await {
+                        tracing::error!(?why, ?packages, "failed to install fonts");
+                    }
+
+                    let mut missing = Vec::new();
+                    for lang_code in &locales {
+                        missing.extend(required_fonts_for_locale(lang_code).await);
+                    }
+
+                    Message::MissingFonts(missing)
+                });
+            }
+
+            Message::RemoveLanguage(id) => {
+                if let Some(language) = self.available_languages.remove(id)
+                    && let Some((config, locales)) = self.config.as_mut()
+                    && let Some(pos) = locales.iter().position(|l| l == &language.lang_code)
+                {
+                    locales.remove(pos);
+                    _ = config.set("system_locales", &locales);
+                }
+            }
+
+            Message::SelectRegion(id) => {
+                if let Some((region, language)) = self
+                    .available_languages
+                    .get(id)
+                    .cloned()
+                    .zip(self.language.clone())
+                {
+                    self.locale_change_requires_relogin_warning(&language.lang_code);
+                    self.region = Some(region.clone());
+
+                    let lang = language.lang_code;
+                    let region = region.lang_code;
+                    let login_screen = self.login_screen();
+
+                    return cosmic::task::future(async move {
+                        if set_locale(lang.clone(), region.clone(), login_screen)
+                            .await
+                            .is_ok()
+                        {
+                            super::shared_config::apply_region_defaults(&region);
+
+                            // Flatpak apps launched afterwards use the new locale without
+                            // restarting the session.
+                            if let Err(why) = sync_session_locale(&lang, &region).await {
+                                tracing::warn!(
+                                    why,
+                                    "failed to update the session locale for Flatpak apps"
+                                );
+                            }
+                        }
+
+                        Message::Refresh(Arc::new(page_reload().await))
+                    });
+                }
+            }
+
+            Message::AddLanguageContext => {
+                self.context = Some(ContextView::AddLanguage);
+                return cosmic::Task::done(crate::app::Message::OpenContextDrawer(self.entity));
+            }
+
+            Message::TranslationCatalogs(catalogs) => {
+                self.translation_catalogs = Some(catalogs);
+                self.update_app_locale_support();
+            }
+
+            Message::AppLocaleSupportContext => {
+                self.context = Some(ContextView::AppLocaleSupport);
+                return cosmic::Task::done(crate::app::Message::OpenContextDrawer(self.entity));
+            }
+
+            Message::AddLanguageSearch(search) => {
+                let previous = std::mem::replace(&mut self.add_language_search, search);
+
+                // Remember searches which found languages once they are cleared.
+                if self.add_language_search.is_empty()
+                    && matches!(self.context, Some(ContextView::AddLanguage))
+                    && self.search_has_results(&previous)
+                {
+                    push_search_history(&mut self.search_history, &previous);
+                    self.save_search_history();
+                }
+            }
+
+            Message::RemoveSearchHistory(index) => {
+                if index < self.search_history.len() {
+                    self.search_history.remove(index);
+                    self.save_search_history();
+                }
+            }
+
+            Message::ClearSearchHistory => {
+                self.search_history.clear();
+                self.save_search_history();
+            }
+
+            Message::BrokenLocales(broken) => {
+                self.broken_locales = broken.into_iter().collect();
+            }
+
+            Message::LanguagePackSizes(sizes) => {
+                self.language_pack_sizes = sizes;
+            }
+
+            Message::LocaleConsistency(consistency) => {
+                self.locale_consistency = consistency;
+            }
+
+            Message::UseSystemLocale => {
+                if let LocaleConsistency::Diverged { .. } =
+                    std::mem::take(&mut self.locale_consistency)
+                    && let Some(system) = self.system_locales.get("LANG")
+                {
+                    let system = system.lang_code.clone();
+
+                    // Make the system locale the primary preferred language, keeping the others.
+                    let locales = match self.config.as_mut() {
+                        Some((config, locales)) => {
+                            locales.retain(|locale| *locale != system);
+                            locales.insert(0, system);
+
+                            if let Err(why) = config.set("system_locales", &locales) {
+                                tracing::error!(?why, "failed to save the preferred languages");
+                            }
+
+                            locales.clone()
+                        }
+                        None => vec![system],
+                    };
+
+                    return cosmic::task::future(async move {
+                        if let Err(why) = set_user_language(build_language_list(&locales)).await {
+                            tracing::error!(
+                                ?why,
+                                "failed to set user language via AccountsService"
+                            );
+                        }
+
+                        Message::Refresh(Arc::new(page_reload().await))
+                    });
+                }
+            }
+
+            Message::UseUserLocale => {
+                if let LocaleConsistency::Diverged { user, .. } =
+                    std::mem::take(&mut self.locale_consistency)
+                {
+                    // Prefer the installed locale which matches the user's language.
+                    let lang = self
+                        .available_languages
+                        .values()
+                        .find(|language| strip_locale_suffix(&language.lang_code) == user)
+                        .map_or(user, |language| language.lang_code.clone());
+                    let region = self
+                        .region
+                        .as_ref()
+                        .map_or_else(|| lang.clone(), |region| region.lang_code.clone());
+                    let login_screen = self.login_screen();
+
+                    return cosmic::task::future(async move {
+                        if let Err(why) = set_locale(lang, region, login_screen).await {
+                            tracing::error!(?why, "failed to apply the user locale system-wide");
+                        }
+
+                        Message::Refresh(Arc::new(page_reload().await))
+                    });
+                }
+            }
+
+            Message::CopyFormattedPreview => {
+                return cosmic::iced::clipboard::write(self.formatted_preview());
+            }
+
+            Message::CopyDockerEnv => {
+                return cosmic::iced::clipboard::write(self.locale_environment_export_for_docker());
+            }
+
+            Message::DismissLanguageSuggestion => {
+                self.suggested_language = None;
+            }
+
+            Message::DismissPersistNotice => {
+                self.locale_not_persisted = false;
+            }
+
+            Message::DismissSortOrderNotice => {
+                self.sort_order_changed = false;
+            }
+
+            Message::DismissReloginNotice => {
+                self.relogin_required = false;
+            }
+
+            Message::DismissInputMethodHint => {
+                self.input_method_hint = None;
+            }
+
+            Message::InstallInputMethod => {
+                if let Some((_, info)) = self.input_method_hint.take() {
+                    return cosmic::Task::future(async move {
+                        let result = tokio::process::Command::new("pkexec")
+                            .args([APT_GET, "install", "-y", info.package.as_str()])
+                            .status()
+                            .await;
+
+                        let package = info.package;
+                        match result {
+                            Ok(status) if status.success() => (),
+                            Ok(status) => {
+                                tracing::error!(%status, package, "failed to install input method");
+                            }
+                            Err(why) => {
+                                tracing::error!(?why, package, "failed to run apt-get");
+                            }
+                        }
+                    })
+                    .discard();
+                }
+            }
+
+            Message::OpenUrl(url) => {
+                if let Err(why) = std::process::Command::new("xdg-open").arg(&url).spawn() {
+                    tracing::error!(?why, url, "failed to open URL");
+                }
+            }
+
+            #[cfg(feature = "xdg-portal")]
+            Message::ExportAnsibleTask => {
+                let task = self.export_settings_for_ansible();
+
+                return cosmic::Task::future(async move {
+                    let path = cosmic::dialog::file_chooser::save::Dialog::new()
+                        .title(fl!("export-ansible-task"))
+                        .file_name("locale.yml")
+                        .modal(false)
+                        .save_file()
+                        .await
+                        .ok()
+                        .and_then(|response| {
+                            response
+                                .0
+                                .uris()
+                                .first()
+                                .filter(|url| url.scheme() == "file")
+                                .and_then(|url| url.to_file_path().ok())
+                        });
+
+                    if let Some(path) = path
+                        && let Err(why) = tokio::fs::write(&path, task).await
+                    {
+                        tracing::error!(?why, ?path, "failed to export the Ansible task");
+                    }
+                })
+                .discard();
+            }
+
+            Message::SetLocaleFromEnvFile(path) => {
+                return cosmic::task::future(async move {
+                    let variables = read_system_locale_conf(&path).await;
+                    Message::LocaleFileLoaded(path, variables)
+                });
+            }
+
+            Message::LocaleFileLoaded(path, variables) => {
+                if variables.is_empty() {
+                    tracing::warn!(?path, "no valid locale variables found");
+                } else {
+                    self.pending_locale_file = Some((path, variables));
+                }
+            }
+
+            Message::CancelLocaleFile => {
+                self.pending_locale_file = None;
+            }
+
+            Message::ApplyLocaleFile => {
+                if let Some((_, variables)) = self.pending_locale_file.take() {
+                    let variables: Vec<String> = variables
+                        .into_iter()
+                        .map(|(var, value)| [var, value].join("="))
+                        .collect();
+
+                    return cosmic::task::future(async move {
+                        if let Err(why) = apply_locale_variables(&variables).await {
+                            tracing::error!(?why, "failed to apply locale variables from file");
+                        }
+
+                        Message::Refresh(Arc::new(page_reload().await))
+                    });
+                }
+            }
+
+            Message::DismissKeyboardLayoutMismatch => {
+                self.keyboard_layout_mismatch_dismissed = true;
+            }
+
+            Message::DismissLocaleFilesConflict => {
+                self.conflicting_locale_files.clear();
+            }
+
+            Message::ConsolidateLocaleFiles => {
+                self.conflicting_locale_files = Vec::new();
+
+                // systemd-localed rewrites the locale config files from the current system locale.
+                let variables: Vec<String> = self
+                    .system_locales
+                    .iter()
+                    .map(|(var, locale)| [var.as_str(), "=", locale.lang_code.as_str()].concat())
+                    .collect();
+
+                return cosmic::task::future(async move {
+                    if let Err(why) = apply_locale_variables(&variables).await {
+                        tracing::error!(?why, "failed to consolidate locale config files");
+                    }
+
+                    Message::Refresh(Arc::new(page_reload().await))
+                });
+            }
+
+            Message::ApplySystemWide => {
+                self.locale_not_persisted = false;
+
+                if let Some(language) = self.language.clone() {
+                    let region = self.region.clone().unwrap_or_else(|| language.clone());
+                    let login_screen = self.login_screen();
+
+                    return cosmic::task::future(async move {
+                        if let Err(why) =
+                            set_locale(language.lang_code, region.lang_code, login_screen).await
+                        {
+                            tracing::error!(?why, "failed to apply the locale system-wide");
+                        }
+
+                        Message::Refresh(Arc::new(page_reload().await))
+                    });
+                }
+            }
+
+            Message::RemoveLcAllDialog => {
+                self.remove_lc_all_dialog = true;
+            }
+
+            Message::CancelRemoveLcAll => {
+                self.remove_lc_all_dialog = false;
+            }
+
+            Message::RemoveLcAll => {
+                self.remove_lc_all_dialog = false;
+
+                return cosmic::task::future(async move {
+                    if let Err(why) = remove_lc_all().await {
+                        tracing::error!(?why, "failed to remove LC_ALL");
+                    }
+
+                    Message::Refresh(Arc::new(page_reload().await))
+                });
+            }
+
+            Message::OtherUsers(users) => {
+                self.other_user_names = users
+                    .iter()
+                    .map(|user| {
+                        if user.real_name.is_empty() {
+                            user.username.clone()
+                        } else {
+                            format!("{} ({})", user.real_name, user.username)
+                        }
+                    })
+                    .collect();
+                self.other_users = users;
+            }
+
+            Message::SelectImportUser(index) => {
+                if let Some(user) = self.other_users.get(index) {
+                    let username = user.username.clone();
+                    return cosmic::task::future(async move {
+                        let language = get_user_locale(&username).await;
+                        Message::UserLocale(username, language)
+                    });
+                }
+            }
+
+            Message::UserLocale(username, language) => match language {
+                Some(language) => self.import_locale = Some((username, language)),
+                None => tracing::warn!(%username, "user has no language to copy"),
+            },
+
+            Message::CancelImportLocale => {
+                self.import_locale = None;
+            }
+
+            Message::ImportLocaleFromUser(username) => {
+                if let Some((_, language)) = self
+                    .import_locale
+                    .take()
+                    .filter(|(pending, _)| *pending == username)
+                {
+                    return self.region_import_from_other_user(language);
+                }
+            }
+
+            Message::ClearAllLanguagesDialog => {
+                self.clear_languages_dialog = true;
+            }
+
+            Message::CancelClearAllLanguages => {
+                self.clear_languages_dialog = false;
+            }
+
+            Message::ClearAllLanguages => {
+                self.clear_languages_dialog = false;
+                return self.clear_all_languages();
+            }
+
+            Message::ToggleChangeHistory => {
+                self.change_history_expanded = !self.change_history_expanded;
+            }
+
+            Message::RestoreLocaleChange(index) => {
+                if let Some(change) = self.locale_change_history.get(index) {
+                    let lang = change.new_lang.clone();
+                    let region = change.new_region.clone();
+                    let login_screen = self.login_screen();
+
+                    return cosmic::task::future(async move {
+                        if let Err(why) = set_locale(lang, region, login_screen).await {
+                            tracing::error!(?why, "failed to restore a previous locale");
+                        }
+
+                        Message::Refresh(Arc::new(page_reload().await))
+                    });
+                }
+            }
+
+            Message::SetAutoSelectRegion(enabled) => {
+                self.auto_select_region = enabled;
+
+                if let Some((config, _)) = self.config.as_ref()
+                    && let Err(why) = config.set(AUTO_SELECT_REGION_KEY, enabled)
+                {
+                    tracing::error!(?why, "failed to save the auto select region setting");
+                }
+            }
+
+            Message::SetLoginScreenLocale(enabled) => {
+                self.set_login_locale = enabled;
+
+                if let Some((config, _)) = self.config.as_ref()
+                    && let Err(why) = config.set(LOGIN_SCREEN_KEY, enabled)
+                {
+                    tracing::error!(?why, "failed to save the login screen locale setting");
+                }
+
+                if let Some((display_manager, language)) =
+                    self.display_manager.zip(self.language.as_ref())
+                {
+                    let lang = enabled.then(|| language.lang_code.clone());
+
+                    return cosmic::task::future(async move {
+                        let result =
+                            configure_login_screen(display_manager, lang.as_deref(), &[]).await;
+                        Message::LoginScreenLocaleResult(
+                            enabled,
+                            result.map_err(|why| why.to_string()),
+                        )
+                    });
+                }
+            }
+
+            Message::LoginScreenLocaleResult(enabled, result) => {
+                if let Err(why) = result {
+                    tracing::error!(why, "failed to configure the login screen locale");
+
+                    // Keep the toggle in line with the display manager's config.
+                    if self.set_login_locale == enabled {
+                        self.set_login_locale = !enabled;
+
+                        if let Some((config, _)) = self.config.as_ref()
+                            && let Err(why) = config.set(LOGIN_SCREEN_KEY, !enabled)
+                        {
+                            tracing::error!(?why, "failed to save the login screen locale setting");
+                        }
+                    }
+                }
+            }
+
+            Message::SetTextDirection(direction) => {
+                self.is_rtl = direction == TextDirection::RightToLeft;
+            }
+
+            Message::ExpandLanguagePopover(id) => {
+                self.expanded_source_popover = id;
+            }
+
+            Message::DownloadLanguagePack(lang_code) => {
+                if matches!(
+                    self.installing_locales.get(&lang_code),
+                    Some(InstallState::Pending | InstallState::InProgress(_))
+                ) {
+                    return cosmic::Task::none();
+                }
+
+                self.installing_locales
+                    .insert(lang_code.clone(), InstallState::Pending);
+
+                return cosmic::Task::stream(cosmic::iced::stream::channel(
+                    1,
+                    |mut emitter| async move {
+                        let _lock = INSTALL_LOCK.lock().await;
+
+                        let state = match install_language_pack(&lang_code, emitter.clone()).await {
+                            Ok(()) => InstallState::Done,
+                            Err(why) => {
+                                tracing::error!(%why, lang_code, "failed to install language pack");
+                                InstallState::Failed(why.to_string())
+                            }
+                        };
+
+                        _ = emitter
+                            .send(Message::InstallProgress { lang_code, state })
+                            .await;
+                    },
+                ))
+                .map(crate::app::Message::from);
+            }
+
+            Message::InstallProgress { lang_code, state } => {
+                let done = state == InstallState::Done;
+                self.installing_locales.insert(lang_code, state);
+
+                if done {
+                    return cosmic::task::future(async move {
+                        Message::Refresh(Arc::new(page_reload().await))
+                    });
+                }
+            }
+
+            Message::InstallAdditionalLanguages => {
+                return cosmic::task::future(async move {
+                    _ = tokio::process::Command::new(GNOME_LANGUAGE_SELECTOR)
+                        .status()
+                        .await;
+
+                    Message::Refresh(Arc::new(page_reload().await))
+                });
+            }
+
+            Message::Refresh(result) => match Arc::into_inner(result).unwrap() {
+                Ok(page_refresh) => {
+                    self.config = page_refresh.config;
+                    self.search_history = self
+                        .config
+                        .as_ref()
+                        .and_then(|(config, _)| config.get(SEARCH_HISTORY_KEY).ok())
+                        .unwrap_or_default();
+                    self.available_languages = page_refresh.available_languages;
+                    self.system_locales = page_refresh.system_locales;
+                    self.language = page_refresh.language;
+                    self.region = page_refresh.region;
+                    self.registry = Some(page_refresh.registry.0);
+                    self.language_selector_available = page_refresh.language_selector_available;
+                    self.language_packs_available = page_refresh.language_packs_available;
+                    self.display_manager = page_refresh.display_manager;
+                    self.keyboard_layout = page_refresh.keyboard_layout;
+                    self.set_login_locale = self
+                        .config
+                        .as_ref()
+                        .and_then(|(config, _)| config.get(LOGIN_SCREEN_KEY).ok())
+                        .unwrap_or(false);
+                    self.auto_select_region = self
+                        .config
+                        .as_ref()
+                        .and_then(|(config, _)| config.get(AUTO_SELECT_REGION_KEY).ok())
+                        .unwrap_or(false);
+                    self.locale_change_history = self
+                        .config
+                        .as_ref()
+                        .and_then(|(config, _)| config.get(LOCALE_HISTORY_KEY).ok())
+                        .unwrap_or_default();
+                    self.preview_scale = self
+                        .config
+                        .as_ref()
+                        .and_then(|(config, _)| config.get(PREVIEW_SCALE_KEY).ok());
+                    self.custom_date_skeleton = self
+                        .config
+                        .as_ref()
+                        .and_then(|(config, _)| config.get(DATE_SKELETON_KEY).ok());
+                    self.suggested_language = page_refresh
+                        .suggested_language
+                        .map(|id| (id, SuggestionSource::KeyboardLayout));
+                    self.languages_configured = page_refresh.languages_configured;
+                    self.locale_not_persisted = page_refresh.locale_not_persisted;
+                    self.conflicting_locale_files = page_refresh.conflicting_locale_files;
+                    self.distribution_locale = page_refresh.distribution_locale;
+                    self.monetary_currency = page_refresh.monetary_currency;
+                    self.numeric_locale = self.icu_locale_from_env("LC_NUMERIC");
+                    self.time_locale = self.icu_locale_from_env("LC_TIME");
+                    self.calendar_systems = self.locale_supported_calendar_systems();
+                    self.calendar_system_names = self
+                        .calendar_systems
+                        .iter()
+                        .map(|id| calendar_system_label(id))
+                        .collect();
+                    self.calendar_system
+                        .take_if(|id| !self.calendar_systems.contains(&id.as_str()));
+                    self.icu_unsupported_locale = self.warn_on_unsupported_locale();
+                    self.refresh_formatting_previews();
+
+                    let mut tasks = vec![self.layout_direction_task()];
+
+                    // Scanning every catalog is slow, so it is only done once.
+                    if self.translation_catalogs.is_some() {
+                        self.update_app_locale_support();
+                    } else {
+                        tasks.push(cosmic::task::future(async {
+                            Message::TranslationCatalogs(Arc::new(
+                                scan_translation_catalogs().await,
+                            ))
+                        }));
+                    }
+
+                    if let Some((_, locales)) = self.config.as_ref() {
+                        let locales = locales.clone();
+                        let pack_locales = locales.clone();
+                        tasks.push(cosmic::task::future(async move {
+                            Message::BrokenLocales(locale_health_check(&locales).await)
+                        }));
+
+                        tasks.push(cosmic::task::future(async move {
+                            Message::LanguagePackSizes(
+                                language_pack_disk_usage(&pack_locales).await,
+                            )
+                        }));
+                    }
+
+                    tasks.push(cosmic::task::future(async {
+                        Message::LocaleConsistency(compare_system_and_user_locale().await)
+                    }));
+
+                    tasks.push(self.detect_locale_env_in_all_shells());
+
+                    tasks.push(cosmic::task::future(async {
+                        Message::OtherUsers(list_other_users().await)
+                    }));
+
+                    // Only ask for the location once, the first time no languages are configured.
+                    // It is remembered once GeoClue answers, so that a lookup which failed to
+                    // reach it is tried again the next time the app is started.
+                    if !self.languages_configured
+                        && !self.location_requested
+                        && self.suggested_language.is_none()
+                        && let Some((config, _)) = self.config.as_ref()
+                        && !config.get::<bool>(LOCATION_SUGGESTION_KEY).unwrap_or(false)
+                    {
+                        self.location_requested = true;
+                        let config = config.clone();
+                        tasks.push(cosmic::task::future(async move {
+                            let locale = match locale_from_geoclue().await {
+                                Ok(locale) => {
+                                    _ = config.set(LOCATION_SUGGESTION_KEY, true);
+                                    locale
+                                }
+                                Err(why) => {
+                                    tracing::warn!(?why, "failed to get the location from GeoClue");
+                                    None
+                                }
+                            };
+
+                            Message::LocationSuggestion(locale)
+                        }));
+                    }
+
+                    return cosmic::Task::batch(tasks);
+                }
+
+                Err(why) => {
+                    tracing::error!(?why, "failed to get locales from the system");
+                }
+            },
+
+            Message::ContinentFilter(continent) => {
+                self.continent_filter = if self.continent_filter == Some(continent) {
+                    None
+                } else {
+                    Some(continent)
+                };
+            }
+
+            Message::ScriptFilter(script) => {
+                self.script_filter = if self.script_filter == Some(script) {
+                    None
+                } else {
+                    Some(script)
+                };
+            }
+
+            Message::WritingDirectionFilter(direction) => {
+                self.writing_direction_filter = if self.writing_direction_filter == Some(direction)
+                {
+                    None
+                } else {
+                    Some(direction)
+                };
+            }
+
+            Message::ToggleLanguageOrderInfo => {
+                self.language_order_info_expanded = !self.language_order_info_expanded;
+            }
+
+            Message::ToggleLookupChain => {
+                self.lookup_chain_expanded = !self.lookup_chain_expanded;
+            }
+
+            Message::ToggleLivePreview(live_preview) => {
+                self.live_preview = live_preview;
+                self.read_clock();
+            }
+
+            Message::Tick => {
+                self.read_clock();
+            }
+
+            Message::ShellLocaleOverrides(overrides) => {
+                self.shell_locale_overrides = overrides;
+            }
+
+            Message::ToggleShellLocaleOverrides => {
+                self.shell_locale_overrides_expanded = !self.shell_locale_overrides_expanded;
+            }
+
+            Message::SetPreviewScale(scale) => {
+                let scale = scale.clamp(0.8, 2.0);
+                self.preview_scale = Some(scale);
+
+                if let Some((config, _)) = self.config.as_ref()
+                    && let Err(why) = config.set(PREVIEW_SCALE_KEY, scale)
+                {
+                    tracing::error!(?why, "failed to save the preview font scale");
+                }
+            }
+
+            Message::SetCalendarSystem(id) => {
+                self.calendar_system = Some(id);
+                self.refresh_formatting_previews();
+            }
+
+            Message::SetDateSkeleton(skeleton) => {
+                let skeleton = skeleton.trim();
+                self.custom_date_skeleton = (!skeleton.is_empty()).then(|| skeleton.to_owned());
+                self.preview_formatters = self.preview_formatters();
+            }
+
+            Message::SaveDateSkeleton => {
+                if let Some((config, _)) = self.config.as_ref()
+                    && let Err(why) = config.set(DATE_SKELETON_KEY, &self.custom_date_skeleton)
+                {
+                    tracing::error!(?why, "failed to save the custom date skeleton");
+                }
+            }
+
+            Message::LanguageHovered(id, entered) => {
+                if entered {
+                    self.hovered_language = Some(id);
+                } else if self.hovered_language == Some(id) {
+                    self.hovered_language = None;
+                }
+            }
+
+            Message::LocationSuggestion(locale) => {
+                if self.suggested_language.is_none()
+                    && let Some(locale) = locale
+                {
+                    self.suggested_language = self
+                        .available_languages
+                        .iter()
+                        .find(|(_, language)| strip_locale_suffix(&language.lang_code) == locale)
+                        .map(|(id, _)| (id, SuggestionSource::Location));
+                }
+            }
+
+            Message::RegionContext => {
+                self.context = Some(ContextView::Region);
+                return cosmic::Task::done(crate::app::Message::OpenContextDrawer(self.entity));
+            }
+
+            Message::SourceContext(context_message) => {
+                self.expanded_source_popover = None;
+
+                if let Some((config, locales)) = self.config.as_mut() {
+                    match context_message {
+                        SourceContext::FixDeprecated(id) => {
+                            let Some(canonical) =
+                                locales.get(id).and_then(|l| check_deprecated_subtags(l))
+                            else {
+                                return cosmic::Task::none();
+                            };
+
+                            locales[id] = canonical;
+                        }
+
+                        SourceContext::Remove(id) => {
+                            let _removed = locales.remove(id);
+                        }
+
+                        SourceContext::SetPosition(from, to) => {
+                            if !move_locale(locales, from, to) {
+                                return cosmic::Task::none();
+                            }
+                        }
+                    }
+
+                    _ = config.set("system_locales", &locales);
+
+                    // Build the LANGUAGE string for AccountsService (colon-separated locales)
+                    let language_list = build_language_list(locales);
+
+                    if let Some(language_code) = locales.first()
+                        && let Some(language) = self
+                            .available_languages
+                            .values()
+                            .find(|lang| &lang.lang_code == language_code)
+                    {
+                        let language = language.clone();
+                        self.locale_change_requires_relogin_warning(&language.lang_code);
+                        self.locale_affects_sorting_warning(&language.lang_code);
+                        self.language = Some(language.clone());
+                        let region = self.region.clone();
+                        let login_screen = self.login_screen();
+
+                        tokio::spawn(async move {
+                            let lang = language.lang_code.clone();
+                            let region = region.unwrap_or(language).lang_code;
+
+                            if set_locale(lang.clone(), region.clone(), login_screen)
+                                .await
+                                .is_ok()
+                                && let Err(why) = sync_session_locale(&lang, &region).await
+                            {
+                                tracing::warn!(
+                                    why,
+                                    "failed to update the session locale for Flatpak apps"
+                                );
+                            }
+
+                            // Set the LANGUAGE variable via AccountsService
+                            if let Err(why) = set_user_language(language_list).await {
+                                tracing::error!(
+                                    ?why,
+                                    "failed to set user language via AccountsService"
+                                );
+                            }
+                        });
+                    }
+                }
+
+                return self.layout_direction_task();
+            }
+        }
+
+        cosmic::Task::none()
+    }
+
+    fn total_installed_languages_count(&self) -> usize {
+        self.available_languages.len()
+    }
+
+    /// Whether the keyboard layout is meant for a different language than the primary language.
+    fn locale_keyboard_layout_mismatch_warning(&self) -> bool {
+        !self.keyboard_layout_mismatch_dismissed
+            && self
+                .keyboard_layout
+                .as_deref()
+                .zip(
+                    self.config
+                        .as_ref()
+                        .and_then(|(_, locales)| locales.first()),
+                )
+                .is_some_and(|(layout, locale)| keyboard_layout_mismatch(layout, locale))
+    }
+
+    /// Selects a newly added language as the region, such as Austria for `de_AT`, if enabled.
+    fn auto_set_region_on_language_add(
+        &mut self,
+        id: DefaultKey,
+    ) -> cosmic::Task<crate::app::Message> {
+        if !self.auto_select_region {
+            return cosmic::Task::none();
+        }
+
+        self.update(Message::SelectRegion(id))
+    }
+
+    /// Shows the relogin notice when running applications cannot follow the change of
+    /// `LANG` to the given language.
+    fn locale_change_requires_relogin_warning(&mut self, lang: &str) {
+        if self
+            .system_locales
+            .get("LANG")
+            .is_some_and(|current| locale_change_requires_relogin(&current.lang_code, lang))
+        {
+            self.relogin_required = true;
+        }
+    }
+
+    /// Shows the sort order notice, at most once, when setting `LANG` changes the collation of
+    /// files because neither `LC_ALL` nor `LC_COLLATE` override it.
+    fn locale_affects_sorting_warning(&mut self, lang: &str) {
+        if self.system_locales.contains_key("LC_ALL")
+            || self.system_locales.contains_key("LC_COLLATE")
+        {
+            return;
+        }
+
+        let Some(current) = self.system_locales.get("LANG") else {
+            return;
+        };
+
+        if strip_locale_suffix(&current.lang_code) == strip_locale_suffix(lang) {
+            return;
+        }
+
+        let Some((config, _)) = self.config.as_ref() else {
+            return;
+        };
+
+        if config.get::<bool>(SORT_ORDER_NOTICE_KEY).unwrap_or(false) {
+            return;
+        }
+
+        if let Err(why) = config.set(SORT_ORDER_NOTICE_KEY, true) {
+            tracing::error!(?why, "failed to save that the sort order notice was shown");
+        }
+
+        self.sort_order_changed = true;
+    }
+
+    /// Explains how applications fall back through the first preferred languages.
+    fn locale_aware_sort_demo(&self) -> Option<String> {
+        let ((_, locales), registry) = self.config.as_ref().zip(self.registry.as_ref())?;
+
+        let mut languages: Vec<String> = Vec::with_capacity(3);
+        for lang_code in locales {
+            let Some(locale) = registry.locale(lang_code) else {
+                continue;
+            };
+
+            let (language, _) = localized_iso_codes(&locale);
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+
+            if languages.len() == 3 {
+                break;
+            }
+        }
+
+        Some(match languages.as_slice() {
+            [] => return None,
+            [first] => fl!("language-order-example", first = first.as_str()),
+            [first, second] => fl!(
+                "language-order-example",
+                "fallback",
+                first = first.as_str(),
+                second = second.as_str()
+            ),
+            [first, second, third, ..] => fl!(
+                "language-order-example",
+                "fallbacks",
+                first = first.as_str(),
+                second = second.as_str(),
+                third = third.as_str()
+            ),
+        })
+    }
+
+    /// The variables which select the language of translations, by precedence, marking the
+    /// one in use and the ones it overrides.
+    fn locale_fallback_chain_visualizer(&self) -> Option<Element<'_, Message>> {
+        let chain = compute_lookup_chain(&self.system_locales);
+        let (in_use, _) = chain.first()?.clone();
+
+        let mut column =
+            widget::column::with_capacity(chain.len()).spacing(cosmic::theme::spacing().space_xxxs);
+
+        for (index, (variable, value)) in chain.into_iter().enumerate() {
+            let status = if index == 0 {
+                fl!("translation-lookup", "in-use")
+            } else {
+                fl!(
+                    "translation-lookup",
+                    "overridden",
+                    variable = in_use.as_str()
+                )
+            };
+
+            column = column.push(
+                widget::row::with_capacity(3)
+                    .push(widget::text::caption(variable).font(cosmic::font::bold()))
+                    .push(widget::text::caption(value).width(cosmic::iced::Length::Fill))
+                    .push(widget::text::caption(status))
+                    .spacing(cosmic::theme::spacing().space_xxs),
+            );
+        }
+
+        Some(column.into())
+    }
+
+    /// A bidirectional sample sentence in the language of `LANG`, if it is right-to-left.
+    fn locale_test_string_for_rtl(&self) -> Option<&'static str> {
+        self.system_locale("LANG")
+            .and_then(|locale| bidi_sample_text(&locale.lang_code))
+    }
+
+    /// The progress of a language pack installation as a percentage.
+    fn install_percent(&self, lang_code: &str) -> f32 {
+        match self.installing_locales.get(lang_code) {
+            Some(InstallState::InProgress(percent)) => f32::from(*percent),
+            Some(InstallState::Done) => 100.0,
+            _ => 0.0,
+        }
+    }
+
+    /// The installation state of a language pack, as shown beside its language.
+    fn locale_install_progress_map(&self, lang_code: &str) -> Option<Element<'_, Message>> {
+        let state = self.installing_locales.get(lang_code)?;
+
+        Some(match state {
+            InstallState::Pending => widget::text::caption(fl!("language-pack", "pending")).into(),
+            InstallState::InProgress(percent) => {
+                cosmic::iced::widget::progress_bar(0.0..=100.0, f32::from(*percent))
+                    .apply(widget::container)
+                    .width(Length::Fixed(80.0))
+                    .into()
+            }
+            InstallState::Done => widget::icon::from_name("emblem-ok-symbolic")
+                .size(16)
+                .into(),
+            InstallState::Failed(why) => widget::tooltip(
+                widget::icon::from_name("dialog-error-symbolic").size(16),
+                widget::text::caption(why.as_str()),
+                widget::tooltip::Position::Left,
+            )
+            .into(),
+        })
+    }
+
+    /// The font scale of the formatting previews.
+    fn locale_preview_scale(&self) -> f32 {
+        self.preview_scale.unwrap_or(1.0)
+    }
+
+    /// The disk space used by the language packs of the preferred languages.
+    fn total_disk_usage_of_language_packs(&self) -> u64 {
+        let mut packages = HashSet::new();
+
+        self.language_pack_sizes
+            .iter()
+            .filter(|(lang_code, _)| packages.insert(language_pack_name(lang_code)))
+            .map(|(_, size)| size)
+            .sum()
+    }
+
+    /// The disk space which uninstalling a language's pack would free, unless another
+    /// preferred language shares the language pack.
+    fn recoverable_disk_space(&self, lang_code: &str) -> Option<u64> {
+        let size = *self.language_pack_sizes.get(lang_code)?;
+        let package = language_pack_name(lang_code);
+
+        let shared = self.config.as_ref().is_some_and(|(_, locales)| {
+            locales
+                .iter()
+                .any(|other| other != lang_code && language_pack_name(other) == package)
+        });
+
+        (!shared).then_some(size)
+    }
+
+    /// Resets the preferred languages and the system locale to the distribution's default
+    /// locale, as given by `default_locale`.
+    fn clear_all_languages(&mut self) -> cosmic::Task<crate::app::Message> {
+        let fallback = self.default_locale().to_owned();
+        let login_screen = self.login_screen();
+
+        if let Some((config, locales)) = self.config.as_mut() {
+            *locales = vec![fallback.clone()];
+            if let Err(why) = config.set("system_locales", &locales) {
+                tracing::error!(?why, "failed to clear the preferred languages");
+            }
+        }
+
+        cosmic::task::future(async move {
+            if let Err(why) = set_locale(fallback.clone(), fallback.clone(), login_screen).await {
+                tracing::error!(?why, "failed to reset the system locale");
+            }
+
+            if let Err(why) = set_user_language(build_language_list(&[fallback])).await {
+                tracing::error!(?why, "failed to set user language via AccountsService");
+            }
+
+            Message::Refresh(Arc::new(page_reload().await))
+        })
+    }
+
+    /// Copies the language of another user, as stored by AccountsService, to this user and the
+    /// system locale.
+    fn region_import_from_other_user(
+        &mut self,
+        language: String,
+    ) -> cosmic::Task<crate::app::Message> {
+        let locales = locales_from_user_language(
+            &language,
+            self.available_languages
+                .values()
+                .map(|language| language.lang_code.as_str()),
+        );
+
+        let Some(lang) = locales.first().cloned() else {
+            tracing::warn!(%language, "none of the user's languages are installed");
+            return cosmic::Task::none();
+        };
+
+        if let Some((config, current)) = self.config.as_mut() {
+            *current = locales.clone();
+            if let Err(why) = config.set("system_locales", &current) {
+                tracing::error!(?why, "failed to save the copied preferred languages");
+            }
+        }
+
+        let region = self
+            .region
+            .as_ref()
+            .map_or_else(|| lang.clone(), |region| region.lang_code.clone());
+        let login_screen = self.login_screen();
+
+        cosmic::task::future(async move {
+            if let Err(why) = set_locale(lang, region, login_screen).await {
+                tracing::error!(?why, "failed to set the copied system locale");
+            }
+
+            if let Err(why) = set_user_language(build_language_list(&locales)).await {
+                tracing::error!(?why, "failed to set user language via AccountsService");
+            }
+
+            Message::Refresh(Arc::new(page_reload().await))
+        })
+    }
+
+    /// Confirms that the language of another user should replace the preferred languages.
+    fn import_locale_dialog(&self) -> Option<Element<'_, crate::pages::Message>> {
+        let (username, language) = self.import_locale.as_ref()?;
+
+        let primary_action = widget::button::suggested(fl!("import-user-locale", "apply"))
+            .on_press(Message::ImportLocaleFromUser(username.clone()));
+
+        let secondary_action =
+            widget::button::standard(fl!("cancel")).on_press(Message::CancelImportLocale);
+
+        Some(
+            widget::dialog()
+                .title(fl!(
+                    "import-user-locale",
+                    "dialog-title",
+                    user = username.as_str()
+                ))
+                .body(fl!(
+                    "import-user-locale",
+                    "dialog-description",
+                    language = language.as_str()
+                ))
+                .icon(widget::icon::from_name("preferences-desktop-locale").size(64))
+                .primary_action(primary_action)
+                .secondary_action(secondary_action)
+                .apply(Element::from)
+                .map(crate::pages::Message::Region),
+        )
+    }
+
+    /// Scans the user's shell startup files for locale variables which override locale1.
+    fn detect_locale_env_in_all_shells(&self) -> cosmic::Task<crate::app::Message> {
+        cosmic::task::future(async {
+            Message::ShellLocaleOverrides(find_shell_locale_overrides().await)
+        })
+    }
+
+    /// Checks which translation catalogs cover the primary language.
+    fn update_app_locale_support(&mut self) {
+        self.app_locale_support = self
+            .translation_catalogs
+            .as_ref()
+            .zip(
+                self.config
+                    .as_ref()
+                    .and_then(|(_, locales)| locales.first()),
+            )
+            .map(|(catalogs, locale)| (locale.clone(), query_app_locale_support(catalogs, locale)));
+    }
+
+    /// Summarizes how many translation catalogs cover the primary language.
+    fn locale_impact_on_application_list(&self) -> Option<String> {
+        let (locale, apps) = self.app_locale_support.as_ref()?;
+        if apps.is_empty() {
+            return None;
+        }
+
+        let language = self
+            .available_languages
+            .values()
+            .find(|language| language.lang_code == *locale)
+            .map_or(locale.as_str(), |language| language.display_name.as_str());
+
+        Some(fl!(
+            "app-locale-support",
+            supported = apps.iter().filter(|app| app.supported).count(),
+            total = apps.len(),
+            locale = language
+        ))
+    }
+
+    /// Whether `LC_ALL` is set, overriding every other locale category.
+    fn locale_conflict_resolver(&self) -> Option<&SystemLocale> {
+        self.system_locales.get("LC_ALL")
+    }
+
+    /// Confirms that `LC_ALL` should be removed from the system locale.
+    fn remove_lc_all_dialog(&self) -> Element<'_, crate::pages::Message> {
+        let primary_action = widget::button::destructive(fl!("lc-all-override", "remove"))
+            .on_press(Message::RemoveLcAll);
+
+        let secondary_action =
+            widget::button::standard(fl!("cancel")).on_press(Message::CancelRemoveLcAll);
+
+        widget::dialog()
+            .title(fl!("lc-all-override", "dialog-title"))
+            .body(fl!("lc-all-override", "dialog-description"))
+            .icon(widget::icon::from_name("dialog-warning-symbolic").size(64))
+            .primary_action(primary_action)
+            .secondary_action(secondary_action)
+            .apply(Element::from)
+            .map(crate::pages::Message::Region)
+    }
+
+    /// The distribution's default locale, which is kept when restoring the defaults.
+    fn default_locale(&self) -> &str {
+        self.distribution_locale
+            .as_deref()
+            .unwrap_or(CLEAR_LANGUAGES_FALLBACK)
+    }
+
+    /// The display name of an available language, or its code if it is not installed.
+    fn language_display_name<'a>(&'a self, lang_code: &'a str) -> &'a str {
+        self.available_languages
+            .values()
+            .find(|language| language.lang_code == lang_code)
+            .map_or(lang_code, |language| language.display_name.as_str())
+    }
+
+    /// Confirms which languages will be removed before restoring the default language.
+    fn clear_all_languages_dialog(&self) -> Element<'_, crate::pages::Message> {
+        let default_locale = self.default_locale();
+
+        let removed = self
+            .config
+            .as_ref()
+            .map_or(&[][..], |(_, locales)| locales.as_slice())
+            .iter()
+            .filter(|lang_code| *lang_code != default_locale)
+            .fold(widget::column::with_capacity(4), |column, lang_code| {
+                column.push(widget::text::body(self.language_display_name(lang_code)))
+            });
+
+        let primary_action = widget::button::destructive(fl!("restore-defaults"))
+            .on_press(Message::ClearAllLanguages);
+
+        let secondary_action =
+            widget::button::standard(fl!("cancel")).on_press(Message::CancelClearAllLanguages);
+
+        widget::dialog()
+            .title(fl!("restore-defaults", "title"))
+            .body(fl!(
+                "restore-defaults",
+                "description",
+                language = self.language_display_name(default_locale)
+            ))
+            .icon(widget::icon::from_name("dialog-warning-symbolic").size(64))
+            .control(removed)
+            .primary_action(primary_action)
+            .secondary_action(secondary_action)
+            .apply(Element::from)
+            .map(crate::pages::Message::Region)
+    }
+
+    /// A Dockerfile snippet which generates and sets the system locale variables.
+    fn locale_environment_export_for_docker(&self) -> String {
+        let variables: Vec<(&str, &str)> = self
+            .system_locales
+            .iter()
+            .map(|(var, locale)| (var.as_str(), locale.lang_code.as_str()))
+            .collect();
+
+        docker_env_snippet(&variables)
+    }
+
+    /// An Ansible task block which reproduces the system and preferred locales.
+    #[cfg(feature = "xdg-portal")]
+    fn export_settings_for_ansible(&self) -> String {
+        let variables: Vec<(&str, &str)> = self
+            .system_locales
+            .iter()
+            .map(|(var, locale)| (var.as_str(), locale.lang_code.as_str()))
+            .collect();
+
+        let locales = self
+            .config
+            .as_ref()
+            .map_or(&[][..], |(_, locales)| locales.as_slice());
+
+        ansible_task(&variables, locales)
+    }
+
+    /// The display manager to apply locale changes to, if enabled.
+    fn login_screen(&self) -> Option<DisplayManager> {
+        self.display_manager.filter(|_| self.set_login_locale)
+    }
+
+    /// The number of preferred languages, when more than the default single language.
+    fn preferred_language_count_badge(&self) -> Option<u32> {
+        u32::try_from(self.configured_languages_count())
+            .ok()
+            .filter(|&count| count > 1)
+    }
+
+    fn configured_languages_count(&self) -> usize {
+        self.config.as_ref().map_or(0, |(_, l)| l.len())
+    }
+
+    /// Emits a layout direction change when the primary language's direction changes.
+    fn layout_direction_task(&self) -> cosmic::Task<crate::app::Message> {
+        let is_rtl = self
+            .config
+            .as_ref()
+            .and_then(|(_, locales)| locales.first())
+            .is_some_and(|lang_code| {
+                locale_text_direction(lang_code) == TextDirection::RightToLeft
+            });
+
+        if is_rtl == self.is_rtl {
+            return cosmic::Task::none();
+        }
+
+        let direction = if is_rtl {
+            TextDirection::RightToLeft
+        } else {
+            TextDirection::LeftToRight
+        };
+
+        cosmic::Task::done(Message::SetTextDirection(direction).into())
+    }
+
+    fn search_has_results(&self, search: &str) -> bool {
+        let search = search.trim().to_lowercase();
+
+        !search.is_empty()
+            && self
+                .available_languages
+                .values()
+                .any(|language| language.display_name.to_lowercase().contains(&search))
+    }
+
+    /// Whether a language matches the lowercased search and the filters of the add language drawer.
+    fn language_matches_filters(&self, language: &SystemLocale, search: &str) -> bool {
+        (search.is_empty() || language.display_name.to_lowercase().contains(search))
+            && self.continent_filter.is_none_or(|continent| {
+                continent_for_locale(&language.lang_code) == Some(continent)
+            })
+            && self
+                .script_filter
+                .is_none_or(|script| locale_script(&language.lang_code) == script)
+            && self.writing_direction_filter.is_none_or(|direction| {
+                locale_matches_writing_direction(&language.lang_code, direction)
+            })
+    }
+
+    /// A label with the number of languages which the search and filters leave visible.
+    fn realtime_search_result_count(&self) -> Element<'_, Message> {
+        let query = self.add_language_search.trim();
+        let search = query.to_lowercase();
+
+        let visible = self
+            .available_languages
+            .values()
+            .filter(|language| self.language_matches_filters(language, &search))
+            .count();
+
+        if visible == 0 && !query.is_empty() {
+            return widget::text::caption(fl!("language-search-results", "none", query = query))
+                .class(cosmic::theme::Text::Accent)
+                .into();
+        }
+
+        widget::text::caption(fl!(
+            "language-search-results",
+            visible = visible,
+            total = self.available_languages.len()
+        ))
+        .into()
+    }
+
+    fn save_search_history(&self) {
+        if let Some((config, _)) = self.config.as_ref()
+            && let Err(why) = config.set(SEARCH_HISTORY_KEY, &self.search_history)
+        {
+            tracing::error!(?why, "failed to save language search history");
+        }
+    }
+
+    /// Splits the available languages into those which are configured, and those which are not.
+    #[allow(clippy::type_complexity)]
+    fn segregate_languages(
+        &self,
+    ) -> (
+        Vec<(DefaultKey, &SystemLocale)>,
+        Vec<(DefaultKey, &SystemLocale)>,
+    ) {
+        self.available_languages.iter().partition(|(_, language)| {
+            self.config
+                .as_ref()
+                .is_some_and(|(_, locales)| locales.contains(&language.lang_code))
+        })
+    }
+
+    fn add_language_view(&self) -> cosmic::Element<'_, crate::pages::Message> {
+        let search_input = &self.add_language_search.trim().to_lowercase();
+
+        let history = (search_input.is_empty() && !self.search_history.is_empty()).then(|| {
+            let chips = self
+                .search_history
+                .iter()
+                .enumerate()
+                .map(|(index, search)| {
+                    widget::row::with_capacity(2)
+                        .push(
+                            widget::button::text(search.as_str())
+                                .on_press(Message::AddLanguageSearch(search.clone())),
+                        )
+                        .push(
+                            widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                                .extra_small()
+                                .on_press(Message::RemoveSearchHistory(index)),
+                        )
+                        .align_y(Alignment::Center)
+                        .apply(widget::container)
+                        .class(cosmic::theme::Container::Card)
+                        .into()
+                })
+                .collect::<Vec<Element<'_, Message>>>();
+
+            widget::flex_row(chips).spacing(cosmic::theme::spacing().space_xxs)
+        });
+
+        let continents = Continent::ALL
+            .into_iter()
+            .map(|continent| {
+                let button = if self.continent_filter == Some(continent) {
+                    widget::button::suggested(continent.label())
+                } else {
+                    widget::button::standard(continent.label())
+                };
+
+                button.on_press(Message::ContinentFilter(continent)).into()
+            })
+            .collect::<Vec<Element<'_, Message>>>();
+
+        let continents = widget::flex_row(continents).spacing(cosmic::theme::spacing().space_xxs);
+
+        let scripts = LocaleScript::FILTERS
+            .into_iter()
+            .map(|script| {
+                let button = if self.script_filter == Some(script) {
+                    widget::button::suggested(script.label())
+                } else {
+                    widget::button::standard(script.label())
+                };
+
+                button.on_press(Message::ScriptFilter(script)).into()
+            })
+            .collect::<Vec<Element<'_, Message>>>();
+
+        let scripts = widget::flex_row(scripts).spacing(cosmic::theme::spacing().space_xxs);
+
+        let writing_directions = WritingDirection::ALL
+            .into_iter()
+            .map(|direction| {
+                let button = if self.writing_direction_filter == Some(direction) {
+                    widget::button::suggested(direction.label())
+                } else {
+                    widget::button::standard(direction.label())
+                };
+
+                button
+                    .on_press(Message::WritingDirectionFilter(direction))
+                    .into()
+            })
+            .collect::<Vec<Element<'_, Message>>>();
+
+        let writing_directions =
+            widget::flex_row(writing_directions).spacing(cosmic::theme::spacing().space_xxs);
+
+        let matches_search =
+            |language: &&SystemLocale| self.language_matches_filters(language, search_input);
+
+        let (installed, available) = self.segregate_languages();
+
+        let group = |header: String, languages: Vec<(DefaultKey, &SystemLocale)>, is_installed| {
+            let mut list = widget::list_column::with_capacity(languages.len());
+
+            for (id, language) in languages {
+                if !matches_search(&language) {
+                    continue;
+                }
+
+                let name = highlighted_text(&language.display_name, search_input, is_installed);
+
+                let label = if self.hovered_language == Some(id) {
+                    widget::column::with_capacity(2)
+                        .push(name)
+                        .push(widget::text::body(sample_text_for_locale(
+                            &language.lang_code,
+                        )))
+                        .into()
+                } else {
+                    name
+                };
+
+                let label = match self.locale_install_progress_map(&language.lang_code) {
+                    Some(state) => widget::row::with_capacity(2)
+                        .push(widget::container(label).width(Length::Fill))
+                        .push(state)
+                        .align_y(Alignment::Center)
+                        .into(),
+                    None => label,
+                };
+
+                let item = selection_context_item_with(
+                    label,
+                    is_installed,
+                    if is_installed {
+                        Message::RemoveLanguage(id)
+                    } else {
+                        Message::AddLanguage(id)
+                    },
+                );
+
+                list = list.add(
+                    widget::mouse_area(item)
+                        .on_enter(Message::LanguageHovered(id, true))
+                        .on_exit(Message::LanguageHovered(id, false)),
+                );
+            }
+
+            widget::column::with_capacity(2)
+                .push(widget::text::heading(header))
+                .push(list)
+                .spacing(cosmic::theme::spacing().space_xxs)
+        };
+
+        let has_installed = installed
+            .iter()
+            .any(|(_, language)| matches_search(language));
+        let has_available = available
+            .iter()
+            .any(|(_, language)| matches_search(language));
+
+        widget::column::with_capacity(6)
+            .push(continents)
+            .push(scripts)
+            .push(writing_directions)
+            .push_maybe(history)
+            .push_maybe(has_installed.then(|| group(fl!("installed-languages"), installed, true)))
+            .push_maybe(has_available.then(|| group(fl!("available-languages"), available, false)))
+            .spacing(cosmic::theme::spacing().space_s)
+            .apply(Element::from)
+            .map(crate::pages::Message::Region)
+    }
+
+    fn icu_locale_from_env(&self, key: &'static str) -> Option<Locale> {
+        self.system_locales
+            .get(key)
+            .or_else(|| self.system_locales.get("LANG"))
+            .map_or("en-US", |locale| &locale.lang_code)
+            .split('.')
+            .next()
+            .unwrap_or("en-US")
+            .replacen('_', "-", 1)
+            .parse::<Locale>()
+            .ok()
+    }
+
+    /// The calendar systems which dates of the time locale can be previewed in.
+    fn locale_supported_calendar_systems(&self) -> Vec<&'static str> {
+        self.time_locale.as_ref().map_or_else(Vec::new, |locale| {
+            supported_calendars_for_locale(&locale.to_string())
+        })
+    }
+
+    /// Formatter preferences of the time locale, in the calendar system chosen for previews.
+    fn date_formatter_preferences(&self, locale: &Locale) -> DateTimeFormatterPreferences {
+        let mut prefs = DateTimeFormatterPreferences::from(locale);
+
+        if let Some(algorithm) = self.calendar_system.as_deref().and_then(calendar_algorithm) {
+            prefs.calendar_algorithm = Some(algorithm);
+        }
+
+        prefs
+    }
+
+    /// The time locale, if ICU has no date formatting data for it.
+    fn warn_on_unsupported_locale(&self) -> Option<String> {
+        let locale = self.time_locale.as_ref()?;
+
+        if icu_has_locale_data(locale) {
+            return None;
+        }
+
+        tracing::warn!(%locale, "ICU data is not available for locale");
+        Some(locale.to_string())
+    }
+
+    /// The date and time of the formatting previews, which is July 4, 1776 at `hour` o'clock
+    /// unless the live preview shows the current local time.
+    fn preview_datetime(&self, hour: u8) -> DateTime<Gregorian> {
+        if self.live_preview
+            && let Some(now) = self.live_preview_now
+        {
+            return now;
+        }
+
+        DateTime {
+            date: Date::try_new_gregorian(1776, 7, 4).unwrap(),
+            time: Time::try_new(hour, 0, 0, 0).unwrap(),
+        }
+    }
+
+    /// Builds the date and time formatters of the previews for the time locale.
+    fn preview_formatters(&self) -> Option<PreviewFormatters> {
+        let locale = self.time_locale.as_ref()?;
+        let prefs = self.date_formatter_preferences(locale);
+
+        Some(PreviewFormatters {
+            custom_date: self
+                .custom_date_skeleton
+                .as_deref()
+                .and_then(|skeleton| date_skeleton_formatter(locale.into(), skeleton)),
+            date: DateTimeFormatter::try_new(prefs, fieldsets::YMD::medium()).ok()?,
+            short_date: DateTimeFormatter::try_new(prefs, fieldsets::YMD::short()).ok()?,
+            full_date: DateTimeFormatter::try_new(prefs, fieldsets::YMDE::long()).ok()?,
+            date_time: DateTimeFormatter::try_new(prefs, fieldsets::YMDT::long()).ok()?,
+            time: DateTimeFormatter::try_new(locale.into(), fieldsets::T::medium()).ok()?,
+            date_order: self
+                .regional_date_order_label()
+                .map(|order| format!("({order})")),
+        })
+    }
+
+    /// Rebuilds the preview formatters and the previews which do not depend on the time.
+    fn refresh_formatting_previews(&mut self) {
+        self.preview_formatters = self.preview_formatters();
+        self.preview_rows = self.static_preview_rows();
+        self.read_clock();
+    }
+
+    /// Reads the clock once, so that the live preview and the timezone abbreviation agree.
+    fn read_clock(&mut self) {
+        let now = jiff::Zoned::now();
+        self.timezone_abbreviation = timezone_abbreviation(&now);
+        self.live_preview_now = self.live_preview.then(|| local_datetime(&now)).flatten();
+    }
+
+    /// Every row of the formatting previews, in the order in which they are shown.
+    fn formatting_preview_rows(&self) -> Vec<PreviewRow> {
+        let mut rows = self.date_preview_rows();
+        rows.extend(self.preview_rows.iter().cloned());
+        rows
+    }
+
+    /// Previews of the reference date, or of the current time when the live preview is on.
+    fn date_preview_rows(&self) -> Vec<PreviewRow> {
+        let Some(formatters) = self.preview_formatters.as_ref() else {
+            return Vec::new();
+        };
+
+        let noon = self.preview_datetime(12);
+        let afternoon = self.preview_datetime(13);
+
+        let date = match formatters.custom_date.as_ref() {
+            Some(dtf) => dtf.format(&noon).to_string(),
+            None => formatters.date.format(&noon).to_string(),
+        };
+
+        let time = formatters.time.format(&afternoon).to_string();
+        let zoned_time = format!("{time} {}", self.timezone_abbreviation);
+
+        vec![
+            PreviewRow {
+                note: formatters.date_order.clone(),
+                ..PreviewRow::new(fl!("formatting", "dates"), date)
+            },
+            PreviewRow::new(
+                fl!("formatting", "short-date"),
+                formatters.short_date.format(&noon).to_string(),
+            ),
+            PreviewRow::new(
+                fl!("formatting", "full-date"),
+                formatters.full_date.format(&noon).to_string(),
+            ),
+            PreviewRow::new(
+                fl!("formatting", "date-and-time"),
+                formatters.date_time.format(&afternoon).to_string(),
+            ),
+            PreviewRow::new(fl!("formatting", "time"), time),
+            PreviewRow::new(fl!("formatting", "time-with-timezone"), zoned_time),
+        ]
+    }
+
+    /// Previews which do not depend on the previewed date and time.
+    fn static_preview_rows(&self) -> Vec<PreviewRow> {
+        let mut rows = Vec::with_capacity(24);
+
+        if let Some(am_pm) = self.formatted_am_pm_notation() {
+            rows.push(PreviewRow {
+                style: PreviewStyle::Caption,
+                ..PreviewRow::new(fl!("formatting", "am-pm"), am_pm)
+            });
+        }
+
+        rows.push(PreviewRow::new(
+            fl!("formatting", "timezone"),
+            self.formatted_timezone_name_localized(),
+        ));
+        rows.push(PreviewRow::new(
+            fl!("formatting", "weekdays"),
+            self.formatted_weekday_names(),
+        ));
+
+        if let Some(weekend) = self.locale_weekend_days_preview() {
+            rows.push(PreviewRow::new(fl!("formatting", "weekend"), weekend));
+        }
+
+        rows.push(PreviewRow::new(
+            fl!("formatting", "months"),
+            self.formatted_month_names(),
+        ));
+
+        if let Some(era) = self.formatted_era() {
+            rows.push(PreviewRow::new(fl!("formatting", "era"), era));
+        }
+
+        if let Some(calendar_system) = self.calendar_system_name() {
+            rows.push(PreviewRow::new(
+                fl!("formatting", "calendar-system"),
+                calendar_system.to_owned(),
+            ));
+        }
+
+        rows.push(PreviewRow::new(
+            fl!("formatting", "duration"),
+            self.formatted_duration(),
+        ));
+        rows.push(PreviewRow {
+            note: self
+                .locale_number_separators()
+                .map(|separators| format!("({separators})")),
+            ..PreviewRow::new(fl!("formatting", "numbers"), self.formatted_numbers())
+        });
+
+        if let Some(digits) = self.locale_number_system_preview() {
+            rows.push(PreviewRow::new(fl!("formatting", "numeral-system"), digits));
+        }
+
+        rows.extend([
+            PreviewRow::new(
+                fl!("formatting", "separators"),
+                self.locale_region_separators(),
+            ),
+            PreviewRow::new(
+                fl!("formatting", "compact-number"),
+                self.formatted_compact_number(),
+            ),
+            PreviewRow::new(
+                fl!("formatting", "negative-number"),
+                self.formatted_negative_number(),
+            ),
+            PreviewRow::new(fl!("formatting", "percent"), self.formatted_percent()),
+            PreviewRow::new(fl!("formatting", "scientific"), self.formatted_scientific()),
+            PreviewRow::new(fl!("formatting", "ordinals"), self.formatted_ordinal()),
+            PreviewRow::new(
+                fl!("formatting", "list-separator"),
+                self.formatted_list_separator(),
+            ),
+            PreviewRow::new(
+                fl!("formatting", "sort-order"),
+                self.formatted_sort_example(),
+            ),
+            PreviewRow::new(
+                fl!("formatting", "currency"),
+                self.preferred_currency_symbol(),
+            ),
+            PreviewRow::new(fl!("formatting", "address"), self.formatted_address()),
+            PreviewRow::new(fl!("formatting", "name"), self.formatted_name()),
+        ]);
+
+        if let Some(sample) = self.locale_test_string_for_rtl() {
+            rows.push(PreviewRow::new(fl!("formatting", "bidi-sample"), sample));
+        }
+
+        // Boxes instead of emoji point to missing font coverage.
+        rows.push(PreviewRow {
+            style: PreviewStyle::Regular,
+            ..PreviewRow::new(fl!("formatting", "emoji"), emoji_display_test())
+        });
+
+        rows
+    }
+
+    /// The name of the calendar system in which dates are previewed.
+    fn calendar_system_name(&self) -> Option<&str> {
+        let selected = self
+            .calendar_system
+            .as_deref()
+            .and_then(|selected| self.calendar_systems.iter().position(|id| *id == selected));
+
+        self.calendar_system_names
+            .get(selected.unwrap_or(0))
+            .map(String::as_str)
+    }
+
+    fn regional_date_order_label(&self) -> Option<String> {
+        self.time_locale
+            .as_ref()
+            .and_then(date_order_for_locale)
+            .map(DateOrder::label)
+    }
+
+    /// The name of the system's timezone, as written in the time locale.
+    fn formatted_timezone_name_localized(&self) -> String {
+        let Some(locale) = self.time_locale.as_ref() else {
+            return String::new();
+        };
+
+        let timezone = jiff::tz::TimeZone::system();
+        let Some(iana_name) = timezone.iana_name() else {
+            return String::new();
+        };
+
+        let Ok(formatter) = NoCalendarFormatter::try_new(
+            DateTimeFormatterPreferences::from(locale),
+            fieldsets::zone::GenericLong,
+        ) else {
+            return iana_name.to_owned();
+        };
+
+        // Generic names depend on the metazone in effect at a given time.
+        let datetime = DateTime {
+            date: Date::try_new_iso(2025, 1, 15).unwrap(),
+            time: Time::try_new(12, 0, 0, 0).unwrap(),
+        };
+
+        let zone = IanaParser::new()
+            .parse(iana_name)
+            .without_offset()
+            .at_date_time_iso(datetime);
+
+        formatter.format(&zone).to_string()
+    }
+
+    /// The locale's notation for the morning and afternoon periods of a 12-hour clock,
+    /// if it uses a 12-hour clock by default.
+    fn formatted_am_pm_notation(&self) -> Option<String> {
+        let locale = self.time_locale.as_ref()?;
+
+        if super::shared_config::default_24h(locale) {
+            return None;
+        }
+
+        let mut prefs = DateTimeFormatterPreferences::from(locale);
+        prefs.hour_cycle = Some(HourCycle::H12);
+
+        let dtf = DateTimeFormatter::try_new(
+            prefs,
+            fieldsets::T::short().with_time_precision(TimePrecision::Minute),
+        )
+        .ok()?;
+
+        let day_period = |hour| {
+            let datetime = DateTime {
+                date: Date::try_new_gregorian(1776, 7, 4).unwrap(),
+                time: Time::try_new(hour, 0, 0, 0).unwrap(),
+            };
+
+            extract_day_period(&dtf.format(&datetime).to_string())
+        };
+
+        Some([day_period(1), day_period(13)].join(" / "))
+    }
+
+    fn formatted_weekday_names(&self) -> String {
+        let Some(locale) = self.time_locale.as_ref() else {
+            return String::new();
+        };
+
+        let prefs = DateTimeFormatterPreferences::from(locale);
+        let Ok(dtf) = DateTimeFormatter::try_new(prefs, fieldsets::E::medium()) else {
+            return String::new();
+        };
+
+        // July 1, 1776 was a Monday; begin the week on the locale's first day.
+        let first_day = super::shared_config::default_first_day(locale) as u8;
+
+        (1..=7)
+            .map(|offset| {
+                let datetime = DateTime {
+                    date: Date::try_new_gregorian(1776, 7, first_day + offset).unwrap(),
+                    time: Time::try_new(12, 0, 0, 0).unwrap(),
+                };
+
+                dtf.format(&datetime).to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The weekend of the time locale's region, such as `Saturday–Sunday`.
+    fn locale_weekend_days_preview(&self) -> Option<String> {
+        let locale = self.time_locale.as_ref()?;
+        let days = weekend_days_for_locale(&locale.to_string());
+
+        let prefs = DateTimeFormatterPreferences::from(locale);
+        let dtf = DateTimeFormatter::try_new(prefs, fieldsets::E::long()).ok()?;
+
+        // July 1, 1776 was a Monday, which ICU numbers as the first weekday.
+        let name = |day: Weekday| {
+            let datetime = DateTime {
+                date: Date::try_new_gregorian(1776, 7, day as u8).unwrap(),
+                time: Time::try_new(12, 0, 0, 0).unwrap(),
+            };
+
+            dtf.format(&datetime).to_string()
+        };
+
+        let contiguous = days.windows(2).all(|pair| next_weekday(pair[0]) == pair[1]);
+
+        match days.as_slice() {
+            [] => None,
+            [day] => Some(name(*day)),
+            [first, .., last] if contiguous => Some([name(*first), name(*last)].join("–")),
+            _ => Some(
+                days.iter()
+                    .map(|day| name(*day))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        }
+    }
+
+    fn formatted_month_names(&self) -> String {
+        let Some(locale) = self.time_locale.as_ref() else {
+            return String::new();
+        };
+
+        let prefs = DateTimeFormatterPreferences::from(locale);
+        let Ok(dtf) = DateTimeFormatter::try_new(prefs, fieldsets::M::medium()) else {
+            return String::new();
+        };
+
+        (1..=12)
+            .map(|month| {
+                let datetime = DateTime {
+                    date: Date::try_new_gregorian(1776, month, 1).unwrap(),
+                    time: Time::try_new(12, 0, 0, 0).unwrap(),
+                };
+
+                dtf.format(&datetime).to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn formatted_numbers(&self) -> String {
+        let Some(locale) = self.numeric_locale.as_ref() else {
+            return String::new();
+        };
+
+        let formatter = DecimalFormatter::try_new(locale.into(), Default::default()).unwrap();
+        let mut value = Decimal::from(123456789);
+        value.multiply_pow10(-2);
+
+        formatter.format(&value).to_string()
+    }
+
+    /// The digits zero to nine of the numeric locale, when they are not ASCII digits.
+    fn locale_number_system_preview(&self) -> Option<String> {
+        let locale = self.system_locale("LC_NUMERIC")?;
+        if !uses_non_ascii_digits(&locale.lang_code) {
+            return None;
+        }
+
+        let locale = super::shared_config::parse_locale(&locale.lang_code)?;
+        let formatter = DecimalFormatter::try_new((&locale).into(), Default::default()).ok()?;
+
+        Some(
+            (0..10)
+                .map(|digit| formatter.format(&Decimal::from(digit)).to_string())
+                .collect(),
+        )
+    }
+
+    fn formatted_negative_number(&self) -> String {
+        let Some(locale) = self.numeric_locale.as_ref() else {
+            return String::new();
+        };
+
+        let Ok(formatter) = DecimalFormatter::try_new(locale.into(), Default::default()) else {
+            return String::new();
+        };
+
+        let mut value = Decimal::from(-123456);
+        value.multiply_pow10(-2);
+
+        formatter.format(&value).to_string()
+    }
+
+    fn formatted_scientific(&self) -> String {
+        const VALUE: f64 = 1_234_567.0;
+
+        let formatter = self
+            .numeric_locale
+            .as_ref()
+            .and_then(|locale| DecimalFormatter::try_new(locale.into(), Default::default()).ok());
+
+        let Some(formatter) = formatter else {
+            return format!("{VALUE:.3e}");
+        };
+
+        // The mantissa is rounded to three fraction digits.
+        let exponent = VALUE.log10().floor() as i32;
+        let mut mantissa = Decimal::from((VALUE / 10f64.powi(exponent) * 1000.0).round() as i64);
+        mantissa.multiply_pow10(-3);
+
+        [
+            formatter.format(&mantissa).to_string(),
+            String::from("×10"),
+            superscript(exponent),
+        ]
+        .concat()
+    }
+
+    /// A large number in the short compact notation of the numeric locale, such as `1.2M`.
+    fn formatted_compact_number(&self) -> String {
+        const VALUE: i64 = 1_200_000;
+
+        let Some(locale) = self.numeric_locale.as_ref() else {
+            return String::new();
+        };
+
+        if icu_has_locale_data(locale)
+            && let Ok(formatter) =
+                CompactDecimalFormatter::try_new_short(locale.into(), Default::default())
+        {
+            return formatter.format_i64(VALUE).to_string();
+        }
+
+        // Without compact data, fall back to engineering notation.
+        let (significand, exponent) = engineering_notation(VALUE);
+        let mut mantissa = Decimal::from(significand);
+        mantissa.multiply_pow10(-1);
+        mantissa.trim_end();
+
+        let mantissa = match DecimalFormatter::try_new(locale.into(), Default::default()) {
+            Ok(formatter) => formatter.format(&mantissa).to_string(),
+            Err(_) => mantissa.to_string(),
+        };
+
+        [mantissa, String::from("×10"), superscript(exponent)].concat()
+    }
+
+    fn formatted_sort_example(&self) -> String {
+        let Some(locale) = self.icu_locale_from_env("LC_COLLATE") else {
+            return String::new();
+        };
+
+        let Ok(collator) = Collator::try_new(
+            CollatorPreferences::from(&locale),
+            CollatorOptions::default(),
+        ) else {
+            return String::new();
+        };
+
+        let mut words = ["zebra", "café", "Ångström", "banana", "apple"];
+        words.sort_by(|a, b| collator.compare(a, b));
+        words.join(", ")
+    }
+
+    fn formatted_percent(&self) -> String {
+        let Some(locale) = self.numeric_locale.as_ref() else {
+            return String::new();
+        };
+
+        // 0.1234 as a percentage.
+        let mut value = Decimal::from(1234);
+        value.multiply_pow10(-2);
+
+        format_percent(locale, &value).unwrap_or_default()
+    }
+
+    fn formatted_list_separator(&self) -> String {
+        let Some(locale) = self.numeric_locale.as_ref() else {
+            return String::new();
+        };
+
+        let Ok(formatter) =
+            ListFormatter::try_new_and(locale.into(), ListFormatterOptions::default())
+        else {
+            return String::new();
+        };
+
+        formatter.format_to_string(["alpha", "beta", "gamma"].into_iter())
+    }
+
+    /// The first few ordinal numbers, such as `1st, 2nd, 3rd, 4th`.
+    fn formatted_ordinal(&self) -> String {
+        let Some(locale) = self.numeric_locale.as_ref() else {
+            return String::new();
+        };
+
+        let rules = PluralRules::try_new_ordinal(PluralRulesPreferences::from(locale)).ok();
+        let language = locale.id.language.as_str();
+
+        (1..=4_usize)
+            .map(|number| {
+                let suffix = rules
+                    .as_ref()
+                    .and_then(|rules| ordinal_suffix(language, rules.category_for(number)));
+
+                match suffix {
+                    Some(suffix) => [number.to_string().as_str(), suffix].concat(),
+                    None => number.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// A fixed duration of 2 hours, 30 minutes and 15 seconds, such as
+    /// `2 hours, 30 minutes, 15 seconds`.
+    fn formatted_duration(&self) -> String {
+        let Some(locale) = self.time_locale.as_ref() else {
+            return String::new();
+        };
+
+        let duration = icu_duration::Duration {
+            hours: 2,
+            minutes: 30,
+            seconds: 15,
+            ..Default::default()
+        };
+
+        format_duration(locale, &duration).unwrap_or_default()
+    }
+
+    /// A date in the native calendar of locales whose years are counted in non-Gregorian eras.
+    fn formatted_era(&self) -> Option<String> {
+        let locale = self.time_locale.as_ref()?;
+
+        let mut prefs = DateTimeFormatterPreferences::from(locale);
+        if prefs.calendar_algorithm.is_none() {
+            prefs.calendar_algorithm = native_calendar_algorithm(locale);
+        }
+
+        let dtf = DateTimeFormatter::try_new(
+            prefs,
+            fieldsets::YMD::long().with_year_style(YearStyle::WithEra),
+        )
+        .ok()?;
+
+        if matches!(
+            dtf.calendar().kind(),
+            AnyCalendarKind::Gregorian | AnyCalendarKind::Iso
+        ) {
+            return None;
+        }
+
+        // Use a recent date, since eras such as those of the Japanese calendar are only
+        // defined for modern dates.
+        let date = Date::try_new_gregorian(2025, 7, 4).unwrap();
+
+        Some(dtf.format(&date).to_string())
+    }
+
+    /// The percentage of locale categories which are explicitly set, rather than inherited from `LANG`.
+    fn locale_completeness_score(&self) -> u8 {
+        locale_completeness(&self.system_locales)
+    }
+
+    /// The system locale of a locale category, falling back to `LANG` when unset.
+    fn system_locale(&self, key: &str) -> Option<&SystemLocale> {
+        self.system_locales
+            .get(key)
+            .or_else(|| self.system_locales.get("LANG"))
+    }
+
+    /// The decimal and thousands separators of the numeric locale.
+    fn locale_number_separators(&self) -> Option<String> {
+        let locale = self.system_locale("LC_NUMERIC")?;
+        let separators = locale_separators_for_region(&locale.lang_code);
+
+        Some(fl!(
+            "formatting",
+            "number-separators",
+            decimal = visible_separator(separators.decimal),
+            thousands = visible_separator(separators.thousands)
+        ))
+    }
+
+    /// The number and list separators of the numeric locale.
+    fn locale_region_separators(&self) -> String {
+        let Some(locale) = self.system_locale("LC_NUMERIC") else {
+            return String::new();
+        };
+
+        let separators = locale_separators_for_region(&locale.lang_code);
+
+        fl!(
+            "formatting",
+            "separators-summary",
+            decimal = visible_separator(separators.decimal),
+            thousands = visible_separator(separators.thousands),
+            list = visible_separator(separators.list),
+            plus = visible_separator(separators.plus_sign),
+            minus = visible_separator(separators.minus_sign)
+        )
+    }
+
+    /// The currency of the monetary locale with a sample amount, such as `USD – $1,234.56`.
+    fn preferred_currency_symbol(&self) -> String {
+        let Some(currency) = self.monetary_currency else {
+            return String::new();
+        };
+
+        let Some(locale) = self.icu_locale_from_env("LC_MONETARY") else {
+            return currency.0.to_string();
+        };
+
+        // 1234.56 in the currency.
+        let mut value = Decimal::from(123_456);
+        value.multiply_pow10(-2);
+
+        match format_currency(&locale, currency, &value) {
+            Some(sample) => format!("{} – {sample}", currency.0),
+            None => currency.0.to_string(),
+        }
+    }
+
+    fn formatted_address(&self) -> String {
+        let format = self
+            .system_locale("LC_ADDRESS")
+            .and_then(|locale| territory_code(&locale.lang_code))
+            .and_then(|territory| {
+                POSTAL_FORMATS
+                    .iter()
+                    .find(|(code, _)| *code == territory)
+                    .map(|(_, format)| *format)
+            })
+            .unwrap_or(DEFAULT_POSTAL_FORMAT);
+
+        format_postal_address(format)
+    }
+
+    fn formatted_name(&self) -> String {
+        let family_name_first = self
+            .system_locale("LC_NAME")
+            .and_then(|locale| territory_code(&locale.lang_code))
+            .is_some_and(|territory| FAMILY_NAME_FIRST_TERRITORIES.contains(&territory));
+
+        if family_name_first {
+            String::from("Smith John")
+        } else {
+            String::from("John Smith")
+        }
+    }
+
+    /// All formatting previews as `label: value` lines, for sharing in bug reports.
+    fn formatted_preview(&self) -> String {
+        self.formatting_preview_rows()
+            .into_iter()
+            .filter(|row| !row.value.is_empty())
+            .map(|row| [row.label.as_str(), ": ", row.value.as_str()].concat())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn region_view(&self) -> cosmic::Element<'_, crate::pages::Message> {
+        let mut list = widget::list_column::with_capacity(self.available_languages.len());
+
+        let search_input = &self.add_language_search.trim().to_lowercase();
+
+        for (id, locale) in &self.available_languages {
+            if search_input.is_empty() || locale.display_name.to_lowercase().contains(search_input)
+            {
+                let is_selected = self
+                    .region
+                    .as_ref()
+                    .is_some_and(|l| l.lang_code == locale.lang_code);
+
+                list = list.add(selection_context_item_with(
+                    highlighted_text(&locale.region_name, search_input, is_selected),
+                    is_selected,
+                    if is_selected {
+                        None
+                    } else {
+                        Some(Message::SelectRegion(id))
+                    },
+                ))
+            }
+        }
+
+        list.apply(Element::from).map(crate::pages::Message::Region)
+    }
+
+    fn app_locale_support_view(&self) -> cosmic::Element<'_, crate::pages::Message> {
+        let apps = self
+            .app_locale_support
+            .as_ref()
+            .map_or(&[][..], |(_, apps)| apps.as_slice());
+
+        apps.iter()
+            .fold(
+                widget::list_column::with_capacity(apps.len()),
+                |list, app| {
+                    list.add(widget::settings::item(
+                        app.app_name.as_str(),
+                        widget::icon::from_name(if app.supported {
+                            "object-select-symbolic"
+                        } else {
+                            "window-close-symbolic"
+                        })
+                        .size(16),
+                    ))
+                },
+            )
+            .apply(Element::from)
+            .map(crate::pages::Message::Region)
+    }
+}
+
+impl page::AutoBind<crate::pages::Message> for Page {}
+
+mod preferred_languages {
+    use crate::pages::time::region::localized_iso_codes;
+
+    use super::Message;
+    use cosmic::{Apply, widget};
+    use cosmic_settings_page::Section;
+
+    pub fn section() -> Section<crate::pages::Message> {
+        crate::slab!(descriptions {
+            pref_lang_desc = fl!("preferred-languages", "desc");
+            add_lang_txt = fl!("add-language");
+            add_txt = fl!("add");
+            apply_txt = fl!("apply-system-wide");
+            consolidate_txt = fl!("consolidate");
+            restore_defaults_txt = fl!("restore-defaults");
+            export_ansible_txt = fl!("export-ansible-task");
+            install_fonts_txt = fl!("missing-fonts", "install");
+            use_system_txt = fl!("locale-diverged", "use-system");
+            remove_lc_all_txt = fl!("lc-all-override", "remove");
+            install_txt = fl!("input-method-hint", "install");
+            learn_more_txt = fl!("input-method-hint", "learn-more");
+            use_user_txt = fl!("locale-diverged", "use-user");
+            login_screen_txt = fl!("apply-to-login-screen");
+            auto_region_txt = fl!("auto-select-region");
+            import_user_txt = fl!("import-user-locale");
+            import_user_desc = fl!("import-user-locale", "desc");
+            language_order_txt = fl!("language-order");
+            open_in_editor_txt = fl!("shell-locale-overrides", "open");
+            lookup_chain_txt = fl!("translation-lookup");
+            app_support_txt = fl!("app-locale-support", "details");
+        });
+
+        Section::default()
+            .title(fl!("preferred-languages"))
+            .descriptions(descriptions)
+            .view::<super::Page>(move |_binder, page, section| {
+                let title = widget::text::body(&section.title).font(cosmic::font::bold());
+
+                let description = widget::text::body(&section.descriptions[pref_lang_desc]);
+
+                let suggestion = page.suggested_language.and_then(|(id, source)| {
+                    let language = page.available_languages.get(id)?;
+                    let language = language.display_name.as_str();
+
+                    Some(crate::widget::notice_item(
+                        "dialog-information-symbolic",
+                        match source {
+                            super::SuggestionSource::KeyboardLayout => {
+                                fl!("language-suggestion", language = language)
+                            }
+                            super::SuggestionSource::Location => {
+                                fl!("language-suggestion", "location", language = language)
+                            }
+                        },
+                        vec![
+                            widget::button::standard(&section.descriptions[add_txt])
+                                .on_press(Message::AddLanguage(id))
+                                .into(),
+                            widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                                .on_press(Message::DismissLanguageSuggestion)
+                                .into(),
+                        ],
+                    ))
+                });
+
+                let language_order = page.locale_aware_sort_demo().map(|example| {
+                    let expand_button = widget::button::icon(widget::icon::from_name(
+                        if page.language_order_info_expanded {
+                            "go-up-symbolic"
+                        } else {
+                            "go-down-symbolic"
+                        },
+                    ))
+                    .extra_small()
+                    .on_press(Message::ToggleLanguageOrderInfo);
+
+                    let header = widget::row::with_capacity(3)
+                        .push(widget::icon::from_name("dialog-information-symbolic").size(16))
+                        .push(
+                            widget::text::body(&section.descriptions[language_order_txt])
+                                .width(cosmic::iced::Length::Fill),
+                        )
+                        .push(expand_button)
+                        .spacing(cosmic::theme::spacing().space_xs)
+                        .align_y(cosmic::iced::Alignment::Center);
+
+                    widget::column::with_capacity(2)
+                        .push(header)
+                        .push_maybe(
+                            page.language_order_info_expanded
+                                .then(|| widget::text::caption(example)),
+                        )
+                        .spacing(cosmic::theme::spacing().space_xxs)
+                        .padding(cosmic::theme::spacing().space_xs)
+                        .apply(widget::container)
+                        .class(cosmic::theme::Container::Card)
+                });
+
+                let lookup_chain = page.locale_fallback_chain_visualizer().map(|chain| {
+                    let expand_button = widget::button::icon(widget::icon::from_name(
+                        if page.lookup_chain_expanded {
+                            "go-up-symbolic"
+                        } else {
+                            "go-down-symbolic"
+                        },
+                    ))
+                    .extra_small()
+                    .on_press(Message::ToggleLookupChain);
+
+                    let header = widget::row::with_capacity(3)
+                        .push(widget::icon::from_name("dialog-information-symbolic").size(16))
+                        .push(
+                            widget::text::body(&section.descriptions[lookup_chain_txt])
+                                .width(cosmic::iced::Length::Fill),
+                        )
+                        .push(expand_button)
+                        .spacing(cosmic::theme::spacing().space_xs)
+                        .align_y(cosmic::iced::Alignment::Center);
+
+                    widget::column::with_capacity(2)
+                        .push(header)
+                        .push_maybe(page.lookup_chain_expanded.then_some(chain))
+                        .spacing(cosmic::theme::spacing().space_xxs)
+                        .padding(cosmic::theme::spacing().space_xs)
+                        .apply(widget::container)
+                        .class(cosmic::theme::Container::Card)
+                });
+
+                let shell_overrides = (!page.shell_locale_overrides.is_empty()).then(|| {
+                    let expand_button = widget::button::icon(widget::icon::from_name(
+                        if page.shell_locale_overrides_expanded {
+                            "go-up-symbolic"
+                        } else {
+                            "go-down-symbolic"
+                        },
+                    ))
+                    .extra_small()
+                    .on_press(Message::ToggleShellLocaleOverrides);
+
+                    let header = widget::row::with_capacity(3)
+                        .push(widget::icon::from_name("dialog-information-symbolic").size(16))
+                        .push(
+                            widget::text::body(fl!(
+                                "shell-locale-overrides",
+                                count = page.shell_locale_overrides.len()
+                            ))
+                            .width(cosmic::iced::Length::Fill),
+                        )
+                        .push(expand_button)
+                        .spacing(cosmic::theme::spacing().space_xs)
+                        .align_y(cosmic::iced::Alignment::Center);
+
+                    let overrides = page.shell_locale_overrides_expanded.then(|| {
+                        page.shell_locale_overrides.iter().fold(
+                            widget::column::with_capacity(page.shell_locale_overrides.len())
+                                .spacing(cosmic::theme::spacing().space_xxs),
+                            |column, shell_override| {
+                                column.push(
+                                    widget::row::with_capacity(2)
+                                        .push(
+                                            widget::text::caption(format!(
+                                                "{}:{} {}={}",
+                                                shell_override.file.display(),
+                                                shell_override.line,
+                                                shell_override.variable,
+                                                shell_override.value
+                                            ))
+                                            .width(cosmic::iced::Length::Fill),
+                                        )
+                                        .push(
+                                            widget::button::link(
+                                                section.descriptions[open_in_editor_txt].clone(),
+                                            )
+                                            .on_press(
+                                                Message::OpenUrl(
+                                                    shell_override.file.display().to_string(),
+                                                ),
+                                            ),
+                                        )
+                                        .spacing(cosmic::theme::spacing().space_xs)
+                                        .align_y(cosmic::iced::Alignment::Center),
+                                )
+                            },
+                        )
+                    });
+
+                    widget::column::with_capacity(2)
+                        .push(header)
+                        .push_maybe(overrides)
+                        .spacing(cosmic::theme::spacing().space_xxs)
+                        .padding(cosmic::theme::spacing().space_xs)
+                        .apply(widget::container)
+                        .class(cosmic::theme::Container::Card)
+                });
+
+                let keyboard_mismatch = page.locale_keyboard_layout_mismatch_warning().then(|| {
+                    crate::widget::notice_item(
+                        "input-keyboard-symbolic",
+                        fl!("keyboard-layout-mismatch"),
+                        vec![
+                            widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                                .on_press(Message::DismissKeyboardLayoutMismatch)
+                                .into(),
+                        ],
+                    )
+                });
+
+                let missing_fonts = (!page.missing_fonts.is_empty()).then(|| {
+                    crate::widget::notice_item(
+                        "dialog-warning-symbolic",
+                        fl!("missing-fonts", fonts = page.missing_fonts.join(", ")),
+                        page.language_packs_available
+                            .then(|| {
+                                widget::button::standard(&section.descriptions[install_fonts_txt])
+                                    .on_press(Message::InstallFonts)
+                                    .into()
+                            })
+                            .into_iter()
+                            .collect(),
+                    )
+                });
+
+                let lc_all = page.locale_conflict_resolver().map(|locale| {
+                    crate::widget::notice_item(
+                        "dialog-warning-symbolic",
+                        fl!("lc-all-override", locale = locale.lang_code.as_str()),
+                        vec![
+                            widget::button::destructive(&section.descriptions[remove_lc_all_txt])
+                                .on_press(Message::RemoveLcAllDialog)
+                                .into(),
+                        ],
+                    )
+                });
+
+                let diverged = match &page.locale_consistency {
+                    super::LocaleConsistency::Consistent => None,
+                    super::LocaleConsistency::Diverged { system, user } => {
+                        Some(crate::widget::notice_item(
+                            "dialog-information-symbolic",
+                            fl!(
+                                "locale-diverged",
+                                system = system.as_str(),
+                                user = user.as_str()
+                            ),
+                            vec![
+                                widget::button::standard(&section.descriptions[use_system_txt])
+                                    .on_press(Message::UseSystemLocale)
+                                    .into(),
+                                widget::button::standard(&section.descriptions[use_user_txt])
+                                    .on_press(Message::UseUserLocale)
+                                    .into(),
+                            ],
+                        ))
+                    }
+                };
+
+                let input_method = page.input_method_hint.as_ref().map(|(language, info)| {
+                    let mut actions = Vec::with_capacity(3);
+
+                    if page.language_packs_available {
+                        actions.push(
+                            widget::button::standard(&section.descriptions[install_txt])
+                                .on_press(Message::InstallInputMethod)
+                                .into(),
+                        );
+                    }
+
+                    actions.push(
+                        widget::button::link(section.descriptions[learn_more_txt].clone())
+                            .on_press(Message::OpenUrl(info.url.clone()))
+                            .into(),
+                    );
+
+                    actions.push(
+                        widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                            .on_press(Message::DismissInputMethodHint)
+                            .into(),
+                    );
+
+                    crate::widget::notice_item(
+                        "input-keyboard-symbolic",
+                        fl!(
+                            "input-method-hint",
+                            language = language.as_str(),
+                            engine = info.engine.as_str()
+                        ),
+                        actions,
+                    )
+                });
+
+                let relogin = page.relogin_required.then(|| {
+                    crate::widget::notice_item(
+                        "system-log-out-symbolic",
+                        fl!("locale-relogin-required"),
+                        vec![
+                            widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                                .on_press(Message::DismissReloginNotice)
+                                .into(),
+                        ],
+                    )
+                });
+
+                let sort_order = page.sort_order_changed.then(|| {
+                    crate::widget::notice_item(
+                        "dialog-information-symbolic",
+                        fl!("sort-order-changed"),
+                        vec![
+                            widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                                .on_press(Message::DismissSortOrderNotice)
+                                .into(),
+                        ],
+                    )
+                });
+
+                let not_persisted = page.locale_not_persisted.then(|| {
+                    crate::widget::notice_item(
+                        "dialog-warning-symbolic",
+                        fl!("locale-not-persisted"),
+                        vec![
+                            widget::button::standard(&section.descriptions[apply_txt])
+                                .on_press(Message::ApplySystemWide)
+                                .into(),
+                            widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                                .on_press(Message::DismissPersistNotice)
+                                .into(),
+                        ],
+                    )
+                });
+
+                let conflict = (!page.conflicting_locale_files.is_empty()).then(|| {
+                    let mut text = fl!("locale-files-conflict");
+
+                    for (path, variables) in &page.conflicting_locale_files {
+                        let variables = variables
+                            .iter()
+                            .map(|(var, value)| [var.as_str(), "=", value.as_str()].concat())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        text.push('\n');
+                        text.push_str(&path.display().to_string());
+                        text.push_str(": ");
+                        text.push_str(&variables);
+                    }
+
+                    crate::widget::notice_item(
+                        "dialog-warning-symbolic",
+                        text,
+                        vec![
+                            widget::button::standard(&section.descriptions[consolidate_txt])
+                                .on_press(Message::ConsolidateLocaleFiles)
+                                .into(),
+                            widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                                .on_press(Message::DismissLocaleFilesConflict)
+                                .into(),
+                        ],
+                    )
+                });
+
+                let mut content = widget::settings::section();
+
+                if let Some(((_config, locales), registry)) =
+                    page.config.as_ref().zip(page.registry.as_ref())
+                {
+                    for (id, lang_code) in locales.iter().enumerate() {
+                        let deprecated = super::check_deprecated_subtags(lang_code);
+
+                        let description = match registry.locale(lang_code) {
+                            Some(locale) => {
+                                let (language, country) = localized_iso_codes(&locale);
+                                format!("{} ({})", language, country)
+                            }
+                            // Deprecated codes may no longer be known to the registry.
+                            None if deprecated.is_some() => lang_code.clone(),
+                            None => continue,
+                        };
+
+                        let status = if let Some(
+                            super::InstallState::Pending | super::InstallState::InProgress(_),
+                        ) = page.installing_locales.get(lang_code)
+                        {
+                            super::LanguageStatus::Installing(page.install_percent(lang_code))
+                        } else if let Some(canonical) = deprecated {
+                            super::LanguageStatus::Deprecated { canonical }
+                        } else if page.broken_locales.contains(lang_code) {
+                            super::LanguageStatus::Missing {
+                                installable: page.language_packs_available,
+                            }
+                        } else {
+                            super::LanguageStatus::Installed
+                        };
+
+                        content = content.add(super::language_element(
+                            id,
+                            lang_code,
+                            description,
+                            page.expanded_source_popover,
+                            status,
+                            page.language_pack_sizes.get(lang_code).copied(),
+                            page.recoverable_disk_space(lang_code),
+                        ));
+                    }
+                }
+
+                let disk_usage = (!page.language_pack_sizes.is_empty()).then(|| {
+                    widget::text::caption(fl!(
+                        "language-pack",
+                        "total-size",
+                        size = super::format_size(page.total_disk_usage_of_language_packs())
+                    ))
+                });
+
+                let app_support = page.locale_impact_on_application_list().map(|summary| {
+                    widget::row::with_capacity(2)
+                        .push(widget::text::caption(summary).width(cosmic::iced::Length::Fill))
+                        .push(
+                            widget::button::link(section.descriptions[app_support_txt].clone())
+                                .on_press(Message::AppLocaleSupportContext),
+                        )
+                        .spacing(cosmic::theme::spacing().space_xs)
+                        .align_y(cosmic::iced::Alignment::Center)
+                });
+
+                let mut options = widget::settings::section().add(
+                    widget::settings::item::builder(&section.descriptions[auto_region_txt])
+                        .toggler(page.auto_select_region, Message::SetAutoSelectRegion),
+                );
+
+                if page.display_manager.is_some() {
+                    options = options.add(
+                        widget::settings::item::builder(&section.descriptions[login_screen_txt])
+                            .toggler(page.set_login_locale, Message::SetLoginScreenLocale),
+                    );
+                }
+
+                if !page.other_user_names.is_empty() {
+                    options = options.add(
+                        widget::settings::item::builder(&section.descriptions[import_user_txt])
+                            .description(&section.descriptions[import_user_desc])
+                            .control(widget::dropdown(
+                                &page.other_user_names,
+                                None,
+                                Message::SelectImportUser,
+                            )),
+                    );
+                }
+
+                let buttons = widget::row::with_capacity(5)
+                    .push(
+                        widget::button::destructive(&section.descriptions[restore_defaults_txt])
+                            .on_press(Message::ClearAllLanguagesDialog),
+                    )
+                    .push(widget::space::horizontal());
+
+                // Exporting needs the file chooser portal.
+                #[cfg(feature = "xdg-portal")]
+                let buttons = buttons.push(
+                    widget::button::standard(&section.descriptions[export_ansible_txt])
+                        .on_press(Message::ExportAnsibleTask),
+                );
+
+                let buttons = buttons.push(
+                    widget::button::standard(&section.descriptions[add_lang_txt])
+                        .on_press(Message::AddLanguageContext),
+                );
+
+                widget::column::with_capacity(20)
+                    .push(title)
+                    .push(description)
+                    .push_maybe(lc_all)
+                    .push_maybe(diverged)
+                    .push_maybe(language_order)
+                    .push_maybe(shell_overrides)
+                    .push_maybe(keyboard_mismatch)
+                    .push_maybe(missing_fonts)
+                    .push_maybe(input_method)
+                    .push_maybe(relogin)
+                    .push_maybe(sort_order)
+                    .push_maybe(not_persisted)
+                    .push_maybe(conflict)
+                    .push_maybe(suggestion)
+                    .push(content)
+                    .push_maybe(disk_usage)
+                    .push_maybe(app_support)
+                    .push(options)
+                    .push(buttons)
+                    .push_maybe(lookup_chain)
+                    .spacing(cosmic::theme::spacing().space_xxs)
+                    .apply(cosmic::Element::from)
+                    .map(Into::into)
+            })
+    }
+}
+
+mod formatting {
+    use super::Message;
+    use cosmic::iced::{Alignment, Length};
+    use cosmic::{Apply, widget};
+    use cosmic_settings_page::Section;
+
+    pub fn section() -> Section<crate::pages::Message> {
+        crate::slab!(descriptions {
+            formatting_txt = fl!("formatting");
+            calendar_system_txt = fl!("formatting", "calendar-system");
+            region_txt = fl!("region");
+            preview_scale_txt = fl!("formatting", "preview-size");
+            live_preview_txt = fl!("formatting", "live-preview");
+            copy_docker_txt = fl!("formatting", "copy-docker");
+            date_skeleton_txt = fl!("formatting", "date-skeleton");
+            date_skeleton_docs_txt = fl!("formatting", "date-skeleton-docs");
+        });
+
+        Section::default()
+            .title(fl!("formatting"))
+            .descriptions(descriptions)
+            .view::<super::Page>(move |_binder, page, section| {
+                let desc = &section.descriptions;
+                let scale = page.locale_preview_scale();
+                let size = super::BASE_PREVIEW_SIZE * scale;
+
+                let formatted_demo = page
+                    .formatting_preview_rows()
+                    .into_iter()
+                    .fold(widget::column::with_capacity(32), |column, row| {
+                        column.push(preview_row(row, size))
+                    })
+                    .spacing(4)
+                    .padding(5.0)
+                    .width(Length::Fill)
+                    .align_x(if page.is_rtl {
+                        Alignment::End
+                    } else {
+                        Alignment::Start
+                    })
+                    .apply(|column| widget::settings::item_row(vec![column.into()]));
+
+                let region = page
+                    .region
+                    .as_ref()
+                    .map(|locale| locale.region_name.as_str())
+                    .unwrap_or("");
+
+                let select_region = crate::widget::go_next_with_item(
+                    &desc[region_txt],
+                    widget::text::body(region),
+                    Message::RegionContext,
+                );
+
+                let title = widget::text::body(&desc[formatting_txt])
+                    .font(cosmic::font::bold())
+                    .width(Length::Fill);
+
+                let copy_button =
+                    widget::button::icon(widget::icon::from_name("edit-copy-symbolic"))
+                        .extra_small()
+                        .on_press(Message::CopyFormattedPreview);
+
+                let docker_button = widget::button::standard(&desc[copy_docker_txt])
+                    .on_press(Message::CopyDockerEnv);
+
+                let header = widget::row::with_capacity(3)
+                    .push(title)
+                    .push(docker_button)
+                    .push(copy_button)
+                    .spacing(cosmic::theme::spacing().space_xxs)
+                    .align_y(Alignment::Center);
+
+                let unsupported = page.icu_unsupported_locale.as_ref().map(|locale| {
+                    crate::widget::notice_item(
+                        "dialog-warning-symbolic",
+                        fl!("icu-unsupported-locale", locale = locale.as_str()),
+                        Vec::new(),
+                    )
+                });
+
+                let preview_scale = widget::settings::item(
+                    &desc[preview_scale_txt],
+                    widget::slider(0.8..=2.0, scale, Message::SetPreviewScale).step(0.1),
+                );
+
+                let live_preview = widget::settings::item::builder(&desc[live_preview_txt])
+                    .toggler(page.live_preview, Message::ToggleLivePreview);
+
+                let calendar_system = (page.calendar_systems.len() > 1).then(|| {
+                    let selected = page.calendar_system.as_deref().and_then(|selected| {
+                        page.calendar_systems.iter().position(|id| *id == selected)
+                    });
+                    let calendar_systems = page.calendar_systems.clone();
+
+                    widget::settings::item(
+                        &desc[calendar_system_txt],
+                        widget::dropdown(
+                            &page.calendar_system_names,
+                            Some(selected.unwrap_or(0)),
+                            move |id| Message::SetCalendarSystem(calendar_systems[id].to_owned()),
+                        ),
+                    )
+                });
+
+                let date_skeleton = widget::settings::item(
+                    &desc[date_skeleton_txt],
+                    widget::row::with_capacity(2)
+                        .push(
+                            widget::text_input(
+                                "yMMMMd",
+                                page.custom_date_skeleton.as_deref().unwrap_or_default(),
+                            )
+                            .on_input(Message::SetDateSkeleton)
+                            .on_submit(|_| Message::SaveDateSkeleton)
+                            .width(Length::Fixed(160.0)),
+                        )
+                        .push(
+                            widget::button::link(desc[date_skeleton_docs_txt].clone())
+                                .on_press(Message::OpenUrl(super::DATE_SKELETON_DOCS.to_owned())),
+                        )
+                        .spacing(cosmic::theme::spacing().space_xxs)
+                        .align_y(Alignment::Center),
+                );
+
+                let mut content = widget::settings::section()
+                    .add(formatted_demo)
+                    .add(preview_scale)
+                    .add(live_preview);
+
+                if let Some(calendar_system) = calendar_system {
+                    content = content.add(calendar_system);
+                }
+
+                let content = content.add(date_skeleton).add(select_region);
+
+                widget::column::with_capacity(3)
+                    .push(header)
+                    .push_maybe(unsupported)
+                    .push(content)
+                    .spacing(cosmic::theme::spacing().space_xxs)
+                    .apply(cosmic::Element::from)
+                    .map(Into::into)
+            })
+    }
+
+    fn preview_row<'a>(row: super::PreviewRow, size: f32) -> cosmic::Element<'a, Message> {
+        let label = [row.label.as_str(), ":"].concat();
+
+        match row.style {
+            super::PreviewStyle::Caption => widget::row::with_capacity(2)
+                .push(widget::text::caption(label))
+                .push(widget::text::caption(row.value))
+                .spacing(4)
+                .into(),
+
+            super::PreviewStyle::Regular => widget::row::with_capacity(2)
+                .push(widget::text::body(label))
+                .push(widget::text::body(row.value).size(size))
+                .spacing(4)
+                .into(),
+
+            super::PreviewStyle::Bold => widget::row::with_capacity(3)
+                .push(widget::text::body(label))
+                .push(
+                    widget::text::body(row.value)
+                        .font(cosmic::font::bold())
+                        .size(size),
+                )
+                .push_maybe(row.note.map(widget::text::caption))
+                .spacing(4)
+                .align_y(Alignment::Center)
+                .into(),
+        }
+    }
+}
+
+mod change_history {
+    use super::Message;
+    use cosmic::iced::Alignment;
+    use cosmic::{Apply, widget};
+    use cosmic_settings_page::Section;
+
+    pub fn section() -> Section<crate::pages::Message> {
+        crate::slab!(descriptions {
+            restore_txt = fl!("locale-change-history", "restore");
+        });
+
+        Section::default()
+            .title(fl!("locale-change-history"))
+            .descriptions(descriptions)
+            .show_while::<super::Page>(|page| !page.locale_change_history.is_empty())
+            .view::<super::Page>(move |_binder, page, section| {
+                let title = widget::text::body(&section.title)
+                    .font(cosmic::font::bold())
+                    .width(cosmic::iced::Length::Fill);
+
+                let expand_button = widget::button::icon(widget::icon::from_name(
+                    if page.change_history_expanded {
+                        "go-up-symbolic"
+                    } else {
+                        "go-down-symbolic"
+                    },
+                ))
+                .extra_small()
+                .on_press(Message::ToggleChangeHistory);
+
+                let header = widget::row::with_capacity(2)
+                    .push(title)
+                    .push(expand_button)
+                    .align_y(Alignment::Center);
+
+                let history = page.change_history_expanded.then(|| {
+                    page.locale_change_history.iter().enumerate().fold(
+                        widget::settings::section(),
+                        |history, (index, change)| {
+                            let timestamp = change
+                                .timestamp
+                                .with_timezone(&chrono::Local)
+                                .format("%Y-%m-%d %H:%M");
+
+                            history.add(
+                                widget::settings::item::builder(format!(
+                                    "{} → {}",
+                                    change.old_lang, change.new_lang
+                                ))
+                                .description(timestamp.to_string())
+                                .control(
+                                    widget::button::standard(&section.descriptions[restore_txt])
+                                        .on_press(Message::RestoreLocaleChange(index)),
+                                ),
+                            )
+                        },
+                    )
+                });
+
+                widget::column::with_capacity(2)
+                    .push(header)
+                    .push_maybe(history)
+                    .spacing(cosmic::theme::spacing().space_xxs)
+                    .apply(cosmic::Element::from)
+                    .map(Into::into)
+            })
+    }
+}
+
+struct Registry(locale::Registry);
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Registry").finish()
+    }
+}
+
+pub async fn page_reload() -> eyre::Result<PageRefresh> {
+    let conn = zbus::Connection::system()
+        .await
+        .wrap_err("zbus system connection error")?;
+
+    let registry = locale::Registry::new().wrap_err("failed to get locale registry")?;
+
+    let system_locales: BTreeMap<String, SystemLocale> = locale1::locale1Proxy::new(&conn)
+        .await
+        .wrap_err("locale1 proxy connect error")?
+        .locale()
+        .await
+        .wrap_err("could not get locale from locale1")?
+        .into_iter()
+        .filter_map(|expression| {
+            let mut fields = expression.split('=');
+            let var = fields.next()?;
+            let lang_code = fields.next()?;
+            let locale = registry.locale(lang_code)?;
+
+            Some((
+                var.to_owned(),
+                localized_locale(&locale, lang_code.to_owned()),
+            ))
+        })
+        .collect();
+
+    let mut languages_configured = true;
+
+    let config = cosmic_config::Config::new("com.system76.CosmicSettings", 1)
+        .ok()
+        .map(|context| {
+            let current = system_locales
+                .get("LANG")
+                .map_or("en_US.UTF-8", |l| l.lang_code.as_str());
+
+            let locales = match validate_and_repair_config(&context, current) {
+                Ok(Some(locales)) => locales,
+                Ok(None) => {
+                    languages_configured = false;
+                    vec![current.to_owned()]
+                }
+                Err(why) => {
+                    tracing::error!(?why, "failed to repair the system_locales config");
+                    vec![current.to_owned()]
+                }
+            };
+
+            (context, locales)
+        });
+
+    let language = system_locales
+        .get("LC_ALL")
+        .or_else(|| system_locales.get("LANG"))
+        .cloned();
+
+    let region = system_locales
+        .get("LC_TIME")
+        .or_else(|| system_locales.get("LANG"))
+        .cloned();
+
+    let monetary_currency = match system_locales
+        .get("LC_MONETARY")
+        .or_else(|| system_locales.get("LANG"))
+    {
+        Some(locale) => locale_currency(&locale.lang_code).await,
+        None => None,
+    };
+
+    // Sort languages by the collation rules of the user's language.
+    let collator = language
+        .as_ref()
+        .and_then(|language| super::shared_config::parse_locale(&language.lang_code))
+        .and_then(|locale| {
+            Collator::try_new(
+                CollatorPreferences::from(&locale),
+                CollatorOptions::default(),
+            )
+            .ok()
+        });
+
+    let mut available_languages_set = BTreeMap::new();
+
+    for line in super::shared_config::list_installed_locales().await {
+        if let Some(locale) = registry.locale(&line) {
+            let language = localized_locale(&locale, line);
+            let sort_key = match collator.as_ref() {
+                Some(collator) => localize_sort_key(&language.display_name, collator),
+                None => language.display_name.clone().into_bytes(),
+            };
+
+            available_languages_set.insert(sort_key, language);
+        }
+    }
+
+    let mut available_languages = SlotMap::new();
+    for language in available_languages_set.into_values() {
+        available_languages.insert(language);
+    }
+
+    let language_selector_available = which::which(GNOME_LANGUAGE_SELECTOR).is_ok();
+    let language_packs_available = language_packs_available().await;
+    let display_manager = detect_display_manager().await;
+
+    let keyboard_layout = get_current_keyboard_layout(&conn).await;
+
+    // Suggest a language from the keyboard layout when none have been configured.
+    let suggested_language = if !languages_configured {
+        keyboard_layout
+            .as_deref()
+            .and_then(keyboard_layout_locale)
+            .and_then(|suggested| {
+                available_languages
+                    .iter()
+                    .find(|(_, language)| strip_locale_suffix(&language.lang_code) == suggested)
+                    .map(|(id, _)| id)
+            })
+    } else {
+        None
+    };
+
+    let locale_files = find_locale_config_files().await;
+
+    let locale_not_persisted = locale_files
+        .first()
+        .is_some_and(|(_, persisted)| locale_differs_from_persisted(persisted, &system_locales));
+
+    let conflicting_locale_files = if locale_files
+        .iter()
+        .skip(1)
+        .any(|(_, variables)| *variables != locale_files[0].1)
+    {
+        locale_files
+    } else {
+        Vec::new()
+    };
+
+    Ok(PageRefresh {
+        config,
+        registry: Registry(registry),
+        language,
+        region,
+        available_languages,
+        system_locales,
+        language_selector_available,
+        language_packs_available,
+        display_manager,
+        keyboard_layout,
+        suggested_language,
+        languages_configured,
+        locale_not_persisted,
+        conflicting_locale_files,
+        distribution_locale: get_distribution_default_locale().await,
+        monetary_currency,
+    })
+}
+
+/// Reads the configured locales, resetting them to the system default if the stored
+/// value cannot be deserialized. Returns `None` if no locales have been configured yet.
+fn validate_and_repair_config(
+    config: &cosmic_config::Config,
+    default: &str,
+) -> Result<Option<Vec<String>>, cosmic_config::Error> {
+    match config.get::<Vec<String>>("system_locales") {
+        Ok(locales) => Ok(Some(locales)),
+
+        Err(why) if why.is_err() => {
+            tracing::warn!(?why, "system_locales config is corrupted, resetting it");
+            let locales = vec![default.to_owned()];
+            config.set("system_locales", &locales)?;
+            Ok(Some(locales))
+        }
+
+        // The config has not been written yet.
+        Err(_) => Ok(None),
+    }
+}
+
+/// Reads the locale variables of a locale.conf file which the system has locales for.
+async fn read_system_locale_conf(path: &Path) -> BTreeMap<String, String> {
+    let contents = match tokio::fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(why) => {
+            tracing::error!(?why, ?path, "failed to read locale file");
+            return BTreeMap::new();
+        }
+    };
+
+    let Ok(registry) = locale::Registry::new() else {
+        return BTreeMap::new();
+    };
+
+    parse_locale_conf(&contents)
+        .into_iter()
+        .filter(|(var, value)| {
+            (var == "LANG" || var.starts_with("LC_")) && registry.locale(value).is_some()
+        })
+        .collect()
+}
+
+/// The locale which the distribution ships as its default, if it declares one.
+async fn get_distribution_default_locale() -> Option<String> {
+    if let Ok(os_release) = tokio::fs::read_to_string("/etc/os-release").await
+        && let Some(locale) = parse_locale_conf(&os_release).remove("DEFAULT_LOCALE")
+    {
+        return Some(locale);
+    }
+
+    let contents = tokio::fs::read_to_string("/usr/lib/locale/default-locale")
+        .await
+        .ok()?;
+
+    let locale = match parse_locale_conf(&contents).remove("LANG") {
+        Some(locale) => locale,
+        None => contents.trim().to_owned(),
+    };
+
+    (!locale.is_empty()).then_some(locale)
+}
+
+/// Reads the locale variables of each locale config file which exists, in order of preference.
+async fn find_locale_config_files() -> Vec<(PathBuf, BTreeMap<String, String>)> {
+    let mut files = Vec::new();
+
+    for path in PERSISTED_LOCALE_FILES {
+        if let Ok(contents) = tokio::fs::read_to_string(path).await {
+            files.push((PathBuf::from(path), parse_locale_conf(&contents)));
+        }
+    }
+
+    files
+}
+
+/// Reloads the page whenever one of the locale config files is modified by another process.
+fn watch_locale_config_files() -> impl futures::Stream<Item = Message> {
+    cosmic::iced::stream::channel(
+        1,
+        |mut emitter: futures::channel::mpsc::Sender<Message>| async move {
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+            let watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+                if let Ok(event) = res
+                    && !event.kind.is_access()
+                    && event.paths.iter().any(|path| {
+                        PERSISTED_LOCALE_FILES
+                            .iter()
+                            .any(|file| path == Path::new(file))
+                    })
+                {
+                    _ = tx.send(());
+                }
+            });
+
+            let Ok(mut watcher) = watcher else {
+                return futures::future::pending().await;
+            };
+
+            // Watch the parent directories, since editors replace files rather than writing to them.
+            for file in PERSISTED_LOCALE_FILES {
+                if let Some(parent) = Path::new(file).parent() {
+                    _ = notify::Watcher::watch(
+                        &mut watcher,
+                        parent,
+                        notify::RecursiveMode::NonRecursive,
+                    );
+                }
+            }
+
+            while rx.recv().await.is_some() {
+                // Wait for a second without changes before reloading.
+                while let Ok(Some(())) =
+                    tokio::time::timeout(Duration::from_secs(1), rx.recv()).await
+                {}
+
+                _ = emitter
+                    .send(Message::Refresh(Arc::new(page_reload().await)))
+                    .await;
+            }
+
+            futures::future::pending().await
+        },
+    )
+}
+
+/// Parses the `KEY=VALUE` pairs of a locale.conf file.
+fn parse_locale_conf(contents: &str) -> BTreeMap<String, String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+            Some((key.trim().to_owned(), value.to_owned()))
+        })
+        .collect()
+}
+
+/// Moves the locale at `from` to `to`, shifting the locales between them.
+///
+/// Returns `false` without modifying the list if either position is out of range.
+fn move_locale(locales: &mut Vec<String>, from: usize, to: usize) -> bool {
+    if from >= locales.len() || to >= locales.len() {
+        return false;
+    }
+
+    let locale = locales.remove(from);
+    locales.insert(to, locale);
+    true
+}
+
+/// The percentage of [`LOCALE_CATEGORIES`] which are explicitly set.
+fn locale_completeness(system_locales: &BTreeMap<String, SystemLocale>) -> u8 {
+    let set = LOCALE_CATEGORIES
+        .iter()
+        .filter(|category| system_locales.contains_key(**category))
+        .count();
+
+    (set * 100 / LOCALE_CATEGORIES.len()) as u8
+}
+
+/// Whether any of the current locale variables differ from those applied at boot.
+fn locale_differs_from_persisted(
+    persisted: &BTreeMap<String, String>,
+    system_locales: &BTreeMap<String, SystemLocale>,
+) -> bool {
+    system_locales
+        .iter()
+        .any(|(var, locale)| persisted.get(var) != Some(&locale.lang_code))
+}
+
+/// A key which sorts display names by the rules of the collator's locale.
+fn localize_sort_key(display_name: &str, collator: &CollatorBorrowed<'_>) -> Vec<u8> {
+    let mut sort_key = Vec::new();
+    _ = collator.write_sort_key_to(display_name, &mut sort_key);
+    sort_key
+}
+
+#[derive(Debug)]
+pub enum InstallError {
+    Spawn(std::io::Error),
+    Failed(std::process::ExitStatus),
+}
+
+impl std::fmt::Display for InstallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spawn(why) => write!(f, "failed to run {APT_GET}: {why}"),
+            Self::Failed(status) => write!(f, "{APT_GET} exited with {status}"),
+        }
+    }
+}
+
+impl std::error::Error for InstallError {}
+
+/// Installs the language pack of a locale with apt-get, reporting its progress.
+async fn install_language_pack(
+    lang_code: &str,
+    mut progress: futures::channel::mpsc::Sender<Message>,
+) -> Result<(), InstallError> {
+    use tokio::io::AsyncBufReadExt;
+
+    let mut child = tokio::process::Command::new("pkexec")
+        .args([APT_GET, "install", "-y", "-o", "APT::Status-Fd=1"])
+        .arg(language_pack_name(lang_code))
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(InstallError::Spawn)?;
+
+    let stdout = child.stdout.take().map(tokio::io::BufReader::new);
+    let stderr = child.stderr.take().map(tokio::io::BufReader::new);
+
+    // Drain stderr concurrently so that apt-get cannot block on a full pipe.
+    let stderr_task = tokio::spawn(async move {
+        if let Some(mut lines) = stderr.map(AsyncBufReadExt::lines) {
+            while let Ok(Some(line)) = lines.next_line().await {
+                tracing::warn!("{APT_GET}: {line}");
+            }
+        }
+    });
+
+    if let Some(mut lines) = stdout.map(AsyncBufReadExt::lines) {
+        while let Ok(Some(line)) = lines.next_line().await {
+            if let Some(percent) = parse_apt_status(&line) {
+                _ = progress
+                    .send(Message::InstallProgress {
+                        lang_code: lang_code.to_owned(),
+                        state: InstallState::InProgress(percent.clamp(0.0, 100.0) as u8),
+                    })
+                    .await;
+            }
+        }
+    }
+
+    _ = stderr_task.await;
+
+    let status = child.wait().await.map_err(InstallError::Spawn)?;
+    if !status.success() {
+        return Err(InstallError::Failed(status));
+    }
+
+    Ok(())
+}
+
+/// The installed size in bytes of the language pack of each locale.
+async fn language_pack_disk_usage(lang_codes: &[String]) -> HashMap<String, u64> {
+    let dpkg = which::which("dpkg-query").is_ok();
+    let mut sizes = HashMap::new();
+
+    for lang_code in lang_codes {
+        let output = if dpkg {
+            tokio::process::Command::new("dpkg-query")
+                .args(["--showformat=${Installed-Size}", "--show"])
+                .arg(language_pack_name(lang_code))
+                .output()
+                .await
+        } else {
+            tokio::process::Command::new("rpm")
+                .args(["-q", "--queryformat", "%{SIZE}"])
+                .arg(rpm_langpack_name(lang_code))
+                .output()
+                .await
+        };
+
+        let Ok(output) = output else {
+            continue;
+        };
+
+        if !output.status.success() {
+            continue;
+        }
+
+        // dpkg reports the installed size in KiB, while rpm reports it in bytes.
+        let unit = if dpkg { 1024 } else { 1 };
+        if let Some(size) = parse_package_size(&String::from_utf8_lossy(&output.stdout), unit) {
+            sizes.insert(lang_code.clone(), size);
+        }
+    }
+
+    sizes
+}
+
+/// Parses the size reported by a package manager query, scaled to bytes.
+fn parse_package_size(output: &str, unit: u64) -> Option<u64> {
+    output.trim().parse::<u64>().ok()?.checked_mul(unit)
+}
+
+/// The name of the Fedora glibc language pack of a locale, such as `glibc-langpack-de`.
+fn rpm_langpack_name(lang_code: &str) -> String {
+    let language = lang_code.split(['_', '.', '@']).next().unwrap_or(lang_code);
+    ["glibc-langpack-", language].concat()
+}
+
+/// Whether the package repositories provide Ubuntu's language packs, which Debian does not.
+async fn language_packs_available() -> bool {
+    if which::which(APT_GET).is_err() {
+        return false;
+    }
+
+    tokio::process::Command::new(APT_CACHE)
+        .args(["policy", &language_pack_name("en_US.UTF-8")])
+        .output()
+        .await
+        .is_ok_and(|output| apt_candidate_available(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Whether `apt-cache policy` output lists an installable version of the package.
+fn apt_candidate_available(policy: &str) -> bool {
+    policy.lines().any(|line| {
+        line.trim()
+            .strip_prefix("Candidate:")
+            .is_some_and(|candidate| candidate.trim() != "(none)")
+    })
+}
+
+/// The name of the Ubuntu language pack of a locale, such as `language-pack-de`.
+fn language_pack_name(lang_code: &str) -> String {
+    let base = strip_locale_suffix(lang_code);
+    let mut parts = base.split('_');
+    let language = parts.next().unwrap_or(&base);
+
+    let suffix = match (language, parts.next()) {
+        ("zh", Some("TW" | "HK" | "MO")) => "zh-hant",
+        ("zh", _) => "zh-hans",
+        _ => language,
+    };
+
+    ["language-pack-", suffix].concat()
+}
+
+/// Parses the overall progress from an `APT::Status-Fd` line, such as
+/// `pmstatus:language-pack-de:42.8571:Installing language-pack-de`.
+fn parse_apt_status(line: &str) -> Option<f32> {
+    let mut fields = line.splitn(4, ':');
+
+    match fields.next()? {
+        "dlstatus" | "pmstatus" => fields.nth(1)?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Returns the configured locales which are not installed on the system.
+async fn locale_health_check(locales: &[String]) -> Vec<String> {
+    let output = match tokio::process::Command::new("locale")
+        .arg("-a")
+        .output()
+        .await
+    {
+        Ok(output) => String::from_utf8(output.stdout).unwrap_or_default(),
+        Err(why) => {
+            tracing::error!(?why, "failed to list installed locales using 'locale -a'");
+            return Vec::new();
+        }
+    };
+
+    let installed: HashSet<String> = output
+        .lines()
+        .map(|line| normalize_codeset(line.trim()))
+        .collect();
+
+    locales
+        .iter()
+        .filter(|locale| !installed.contains(&normalize_codeset(locale)))
+        .cloned()
+        .collect()
+}
+
+/// Normalizes the codeset of a locale string as glibc does.
+/// "de_DE.UTF-8" -> "de_DE.utf8"
+/// "ca_ES.UTF-8@valencia" -> "ca_ES.utf8@valencia"
+fn normalize_codeset(locale: &str) -> String {
+    let (name, modifier) = match locale.split_once('@') {
+        Some((name, modifier)) => (name, Some(modifier)),
+        None => (locale, None),
+    };
+
+    let mut normalized = match name.split_once('.') {
+        Some((language, codeset)) => {
+            let codeset: String = codeset
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_lowercase())
+                .collect();
+            [language, ".", &codeset].concat()
+        }
+        None => name.to_owned(),
+    };
+
+    if let Some(modifier) = modifier {
+        normalized.push('@');
+        normalized.push_str(modifier);
+    }
+
+    normalized
+}
+
+/// The default X11 keyboard layout, as shown by `localectl status`.
+async fn get_current_keyboard_layout(conn: &zbus::Connection) -> Option<String> {
+    let layouts = locale1::locale1Proxy::new(conn)
+        .await
+        .ok()?
+        .x11_layout()
+        .await
+        .ok()?;
+
+    // Multiple layouts are comma-separated, with the first being the default.
+    let layout = layouts.split(',').next()?.trim();
+    (!layout.is_empty()).then(|| layout.to_owned())
+}
+
+/// Suggests a locale for a keyboard layout, such as `de_DE` for `de`.
+fn keyboard_layout_locale(layout: &str) -> Option<&'static str> {
+    KEYBOARD_LAYOUT_LOCALES
+        .iter()
+        .find(|(name, _)| *name == layout)
+        .map(|(_, locale)| *locale)
+}
+
+/// Whether a keyboard layout is meant for another language than that of a locale.
+///
+/// Languages without a layout of their own are never considered mismatched.
+fn keyboard_layout_mismatch(layout: &str, locale: &str) -> bool {
+    let language = |locale: &str| {
+        locale
+            .split(['_', '.', '@'])
+            .next()
+            .unwrap_or("")
+            .to_owned()
+    };
+    let locale_language = language(locale);
+
+    let has_layout = KEYBOARD_LAYOUT_LOCALES
+        .iter()
+        .any(|(_, layout_locale)| language(layout_locale) == locale_language);
+
+    has_layout && keyboard_layout_locale(layout).is_some_and(|l| language(l) != locale_language)
+}
+
+/// Suggests a locale for the country the system is located in, as reported by GeoClue.
+///
+/// Returns `None` if GeoClue declines to locate the system or no locale is known for its
+/// country, and an error if GeoClue could not be reached or did not report a location in time.
+async fn locale_from_geoclue() -> eyre::Result<Option<String>> {
+    use zbus::zvariant::OwnedObjectPath;
+
+    let conn = zbus::Connection::system().await?;
+
+    let manager = zbus::Proxy::new(
+        &conn,
+        GEOCLUE,
+        "/org/freedesktop/GeoClue2/Manager",
+        "org.freedesktop.GeoClue2.Manager",
+    )
+    .await?;
+
+    let client_path: OwnedObjectPath = manager.call("GetClient", &()).await?;
+
+    let client = zbus::Proxy::new(
+        &conn,
+        GEOCLUE,
+        client_path,
+        "org.freedesktop.GeoClue2.Client",
+    )
+    .await?;
+
+    client
+        .set_property("DesktopId", "com.system76.CosmicSettings")
+        .await?;
+
+    // Country level accuracy is all that is needed to suggest a locale.
+    client.set_property("RequestedAccuracyLevel", 1u32).await?;
+
+    let mut updates = client.receive_signal("LocationUpdated").await?;
+
+    // Location services are disabled, or access to them was denied.
+    if let Err(why) = client.call::<_, _, ()>("Start", &()).await {
+        tracing::info!(?why, "GeoClue declined to locate the system");
+        return Ok(None);
+    }
+
+    let update = tokio::time::timeout(GEOCLUE_TIMEOUT, updates.next()).await;
+    _ = client.call::<_, _, ()>("Stop", &()).await;
+
+    let update = update
+        .wrap_err("timed out waiting for a location")?
+        .ok_or_else(|| eyre::eyre!("GeoClue stopped sending location updates"))?;
+
+    let (_previous, location_path): (OwnedObjectPath, OwnedObjectPath) =
+        update.body().deserialize()?;
+
+    let location = zbus::Proxy::new(
+        &conn,
+        GEOCLUE,
+        location_path,
+        "org.freedesktop.GeoClue2.Location",
+    )
+    .await?;
+
+    let latitude: f64 = location.get_property("Latitude").await?;
+    let longitude: f64 = location.get_property("Longitude").await?;
+
+    let zone_tab = tokio::fs::read_to_string("/usr/share/zoneinfo/zone.tab")
+        .await
+        .wrap_err("failed to read zone.tab")?;
+
+    Ok(
+        nearest_country(&zone_tab, latitude, longitude).and_then(|country| {
+            COUNTRY_LOCALES
+                .iter()
+                .find(|(code, _)| *code == country)
+                .map(|(_, locale)| (*locale).to_owned())
+        }),
+    )
+}
+
+/// The country of the `zone.tab` entry whose principal city is nearest to a position.
+fn nearest_country(zone_tab: &str, latitude: f64, longitude: f64) -> Option<&str> {
+    zone_tab
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let country = fields.next()?;
+            let (lat, lon) = parse_iso6709(fields.next()?)?;
+
+            // An equirectangular approximation is precise enough to compare distances.
+            let x = (lon - longitude) * ((lat + latitude) / 2.0).to_radians().cos();
+            let y = lat - latitude;
+
+            Some((country, x * x + y * y))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(country, _)| country)
+}
+
+/// Parses ISO 6709 coordinates such as `+4042-07400` into degrees of latitude and longitude.
+fn parse_iso6709(coordinates: &str) -> Option<(f64, f64)> {
+    let split = coordinates.get(1..)?.find(['+', '-'])? + 1;
+    let (latitude, longitude) = coordinates.split_at(split);
+
+    Some((
+        iso6709_degrees(latitude, 2)?,
+        iso6709_degrees(longitude, 3)?,
+    ))
+}
+
+/// Parses a signed `DDMM[SS]` or `DDDMM[SS]` angle into degrees.
+fn iso6709_degrees(angle: &str, degree_digits: usize) -> Option<f64> {
+    let (sign, digits) = angle.split_at_checked(1)?;
+
+    let sign = match sign {
+        "+" => 1.0,
+        "-" => -1.0,
+        _ => return None,
+    };
+
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let degrees: f64 = digits.get(..degree_digits)?.parse().ok()?;
+    let minutes: f64 = digits.get(degree_digits..degree_digits + 2)?.parse().ok()?;
+    let seconds: f64 = match digits.get(degree_digits + 2..)? {
+        "" => 0.0,
+        seconds => seconds.parse().ok()?,
+    };
+
+    Some(sign * (degrees + minutes / 60.0 + seconds / 3600.0))
+}
+
+/// A phrase written in the script of a locale, such as a pangram.
+fn sample_text_for_locale(lang_code: &str) -> &'static str {
+    let base = strip_locale_suffix(lang_code);
+    let language = base.split('_').next().unwrap_or(&base);
+
+    SAMPLE_TEXTS
+        .iter()
+        .find(|(code, _)| *code == base)
+        .or_else(|| SAMPLE_TEXTS.iter().find(|(code, _)| *code == language))
+        .map_or(DEFAULT_SAMPLE_TEXT, |(_, text)| *text)
+}
+
+static DEFAULT_SAMPLE_TEXT: &str = "The quick brown fox jumps over the lazy dog.";
+
+/// The days which the locale's region treats as the weekend, beginning with the first of them.
+fn weekend_days_for_locale(locale: &str) -> Vec<Weekday> {
+    let Some(locale) = super::shared_config::parse_locale(locale) else {
+        return Vec::new();
+    };
+
+    let Ok(week_info) = WeekInformation::try_new(WeekPreferences::from(&locale)) else {
+        return Vec::new();
+    };
+
+    // ICU lists the weekend from the first day of the week, which would put Sunday
+    // before Saturday in the United States.
+    let mut days = week_info.weekend().collect::<Vec<_>>();
+    let start = days
+        .iter()
+        .position(|day| !days.contains(&previous_weekday(*day)))
+        .unwrap_or(0);
+    days.rotate_left(start);
+    days
+}
+
+fn next_weekday(day: Weekday) -> Weekday {
+    Weekday::from_days_since_sunday(day as isize + 1)
+}
+
+fn previous_weekday(day: Weekday) -> Weekday {
+    Weekday::from_days_since_sunday(day as isize - 1)
+}
+
+/// Emoji to check that a font covers them, since they are shown as boxes otherwise.
+fn emoji_display_test() -> String {
+    String::from("✅❌🌍🕐")
+}
+
+/// A sentence mixing right-to-left text with an embedded left-to-right word, for locales
+/// written from right to left.
+fn bidi_sample_text(locale: &str) -> Option<&'static str> {
+    let language = locale.split(['_', '.', '@']).next()?;
+
+    BIDI_SAMPLE_TEXTS
+        .iter()
+        .find(|(code, _)| *code == language)
+        .map(|(_, text)| *text)
+}
+
+/// The font packages which a locale's script requires but which are not installed.
+async fn required_fonts_for_locale(lang_code: &str) -> Vec<String> {
+    let requirements = font_requirements(lang_code);
+    if requirements.is_empty() {
+        return Vec::new();
+    }
+
+    let language = requirements[0].0;
+
+    // Any installed font which fontconfig knows to cover the language will do.
+    let covered = match tokio::process::Command::new("fc-list")
+        .args([&format!(":lang={language}"), "family"])
+        .output()
+        .await
+    {
+        Ok(output) if output.status.success() => {
+            Some(!String::from_utf8_lossy(&output.stdout).trim().is_empty())
+        }
+        _ => None,
+    };
+
+    let mut missing = Vec::new();
+    for &(_, file_prefix, package) in requirements {
+        let installed = match covered {
+            Some(covered) => covered,
+            None => font_file_exists(Path::new("/usr/share/fonts"), file_prefix).await,
+        };
+
+        if !installed && !missing.iter().any(|p| p == package) {
+            missing.push(package.to_owned());
+        }
+    }
+
+    missing
+}
+
+/// The fonts required by the script of a locale's language.
+fn font_requirements(lang_code: &str) -> &'static [(&'static str, &'static str, &'static str)] {
+    let language = lang_code.split(['_', '.', '@']).next().unwrap_or_default();
+
+    let start = LOCALE_FONTS.partition_point(|(code, ..)| *code < language);
+    let end = start + LOCALE_FONTS[start..].partition_point(|(code, ..)| *code == language);
+
+    &LOCALE_FONTS[start..end]
+}
+
+/// Searches a font directory for a font file whose name starts with the prefix.
+async fn font_file_exists(dir: &Path, prefix: &str) -> bool {
+    let mut dirs = vec![dir.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(mut entries) = tokio::fs::read_dir(&dir).await else {
+            continue;
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if entry.file_name().to_string_lossy().starts_with(prefix) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Installs font packages with apt-get.
+async fn install_font_packages(packages: &[String]) -> eyre::Result<()> {
+    let status = tokio::process::Command::new("pkexec")
+        .args([APT_GET, "install", "-y"])
+        .args(packages)
+        .status()
+        .await
+        .wrap_err("failed to run apt-get")?;
+
+    if !status.success() {
+        return Err(eyre::eyre!("apt-get exited with {status}"));
+    }
+
+    Ok(())
+}
+
+/// The input method needed to type a locale's language, if its keyboard layouts are not enough.
+fn recommended_input_method(lang_code: &str) -> Option<InputMethodInfo> {
+    let base = strip_locale_suffix(lang_code);
+    let language = base.split('_').next().unwrap_or(&base);
+
+    let (engine, url) = match (language, base.split('_').nth(1)) {
+        ("ja", _) => ("ibus-mozc", "https://github.com/google/mozc"),
+        ("zh", Some("TW" | "HK" | "MO")) => {
+            ("ibus-chewing", "https://github.com/chewing/ibus-chewing")
+        }
+        ("zh", _) => (
+            "ibus-libpinyin",
+            "https://github.com/libpinyin/ibus-libpinyin",
+        ),
+        ("ko", _) => ("ibus-hangul", "https://github.com/libhangul/ibus-hangul"),
+        ("vi", _) => ("ibus-unikey", "https://github.com/vn-input/ibus-unikey"),
+        ("ar" | "fa" | "ur", _) => ("ibus-m17n", "https://github.com/ibus/ibus-m17n"),
+        _ => return None,
+    };
+
+    Some(InputMethodInfo {
+        engine: engine.to_owned(),
+        package: engine.to_owned(),
+        url: url.to_owned(),
+    })
+}
+
+/// The continent of the territory of a locale string, such as Europe for `de_DE.UTF-8`.
+fn continent_for_locale(locale: &str) -> Option<Continent> {
+    let territory = territory_code(locale)?;
+
+    TERRITORY_CONTINENTS
+        .iter()
+        .find(|(code, _)| *code == territory)
+        .map(|(_, continent)| *continent)
+}
+
+/// Whether the locale of a preferred language is installed on the system.
+#[derive(Clone, Debug, PartialEq)]
+enum LanguageStatus {
+    Installed,
+    /// The locale uses a deprecated language subtag, such as `iw` for Hebrew.
+    Deprecated {
+        canonical: String,
+    },
+    Missing {
+        installable: bool,
+    },
+    /// A language pack is being installed, with its progress as a percentage.
+    Installing(f32),
+}
+
+fn language_element(
+    id: usize,
+    lang_code: &str,
+    description: String,
+    expanded_source_popover: Option<usize>,
+    status: LanguageStatus,
+    pack_size: Option<u64>,
+    recoverable: Option<u64>,
+) -> cosmic::Element<'static, Message> {
+    let expanded = expanded_source_popover.is_some_and(|expanded_id| expanded_id == id);
+
+    let status: Option<Element<'static, Message>> = match status {
+        LanguageStatus::Installed => None,
+
+        LanguageStatus::Missing { installable } => Some(
+            widget::row::with_capacity(2)
+                .push(
+                    widget::text::caption(fl!("language-not-installed")).class(
+                        cosmic::theme::Text::Color(
+                            cosmic::theme::active()
+                                .cosmic()
+                                .destructive_text_color()
+                                .into(),
+                        ),
+                    ),
+                )
+                .push_maybe(installable.then(|| {
+                    widget::button::standard(fl!("download-language-pack"))
+                        .on_press(Message::DownloadLanguagePack(lang_code.to_owned()))
+                }))
+                .spacing(cosmic::theme::spacing().space_xs)
+                .align_y(Alignment::Center)
+                .into(),
+        ),
+
+        LanguageStatus::Deprecated { canonical } => Some(
+            widget::row::with_capacity(2)
+                .push(widget::text::caption(fl!(
+                    "deprecated-language-code",
+                    canonical = canonical
+                )))
+                .push(
+                    widget::button::standard(fl!("deprecated-language-code", "fix"))
+                        .on_press(Message::SourceContext(SourceContext::FixDeprecated(id))),
+                )
+                .spacing(cosmic::theme::spacing().space_xs)
+                .align_y(Alignment::Center)
+                .into(),
+        ),
+
+        LanguageStatus::Installing(progress) => Some(
+            cosmic::iced::widget::progress_bar(0.0..=100.0, progress)
+                .apply(widget::container)
+                .width(Length::Fixed(120.0))
+                .into(),
+        ),
+    };
+
+    let charset = locale_character_set_display(lang_code);
+
+    let control = widget::row::with_capacity(3)
+        .push_maybe(charset)
+        .push_maybe(status)
+        .push(popover_button(id, expanded, recoverable))
+        .spacing(cosmic::theme::spacing().space_xs)
+        .align_y(Alignment::Center);
+
+    let mut item = widget::settings::item::builder(description);
+    if let Some(size) = pack_size {
+        item = item.description(fl!("language-pack", "size", size = format_size(size)));
+    }
+
+    item.control(control).into()
+}
+
+/// Whether running applications need a new session to pick up a locale change, which
+/// is the case when the language or its script changes.
+fn locale_change_requires_relogin(old: &str, new: &str) -> bool {
+    let (Some(old), Some(new)) = (language_and_script(old), language_and_script(new)) else {
+        return old != new;
+    };
+
+    old != new
+}
+
+/// The language of a locale string and the script it is written in, such as
+/// `sr` and `Latn` for `sr_RS.UTF-8@latin`.
+fn language_and_script(lang_code: &str) -> Option<(Language, Option<Script>)> {
+    let mut langid = super::shared_config::parse_locale(lang_code)?.id;
+
+    match lang_code.rsplit_once('@').map(|(_, modifier)| modifier) {
+        Some("latin") => langid.script = "Latn".parse().ok(),
+        Some("cyrillic") => langid.script = "Cyrl".parse().ok(),
+        _ => {
+            LocaleExpander::new_common().maximize(&mut langid);
+        }
+    }
+
+    Some((langid.language, langid.script))
+}
+
+/// A badge with the charset of a locale, highlighted as a warning unless it is UTF-8.
+fn locale_character_set_display(lang_code: &str) -> Option<Element<'static, Message>> {
+    let charset = extract_charset(lang_code);
+    if charset.is_empty() {
+        return None;
+    }
+
+    let badge = widget::text::caption(charset.to_owned());
+
+    Some(if is_utf8_charset(charset) {
+        badge.into()
+    } else {
+        badge
+            .class(cosmic::theme::Text::Color(
+                cosmic::theme::active().cosmic().warning_text_color().into(),
+            ))
+            .into()
+    })
+}
+
+/// The charset of a locale string, such as `UTF-8` for `de_DE.UTF-8@euro`, or an empty
+/// string if it has none.
+fn extract_charset(lang_code: &str) -> &str {
+    lang_code
+        .split_once('.')
+        .map_or("", |(_, rest)| rest.split('@').next().unwrap_or(rest))
+}
+
+/// Whether a charset names UTF-8, which glibc also spells `utf8`.
+fn is_utf8_charset(charset: &str) -> bool {
+    charset.eq_ignore_ascii_case("UTF-8") || charset.eq_ignore_ascii_case("utf8")
+}
+
+/// The script a locale is written in, from its script subtag or its language's likely script.
+fn locale_script(lang_code: &str) -> LocaleScript {
+    let Some((_, Some(script))) = language_and_script(lang_code) else {
+        return LocaleScript::Other;
+    };
+
+    match script.as_str() {
+        "Latn" => LocaleScript::Latin,
+        "Cyrl" => LocaleScript::Cyrillic,
+        "Arab" => LocaleScript::Arabic,
+        "Hans" | "Hant" | "Hani" | "Jpan" | "Kore" | "Hang" => LocaleScript::Cjk,
+        "Deva" => LocaleScript::Devanagari,
+        "Grek" => LocaleScript::Greek,
+        "Hebr" => LocaleScript::Hebrew,
+        "Thai" => LocaleScript::Thai,
+        _ => LocaleScript::Other,
+    }
+}
+
+/// The horizontal direction in which the script of a locale is written.
+fn writing_direction_for_locale(lang_code: &str) -> WritingDirection {
+    match locale_text_direction(lang_code) {
+        TextDirection::LeftToRight => WritingDirection::LeftToRight,
+        TextDirection::RightToLeft => WritingDirection::RightToLeft,
+    }
+}
+
+/// Whether a locale is written in a direction. CJK scripts are written both horizontally and
+/// in vertical lines, so their locales also count as top to bottom.
+fn locale_matches_writing_direction(lang_code: &str, direction: WritingDirection) -> bool {
+    match direction {
+        WritingDirection::TopToBottom => locale_script(lang_code) == LocaleScript::Cjk,
+        direction => writing_direction_for_locale(lang_code) == direction,
+    }
+}
+
+/// The locale string with its language subtag canonicalized, if the subtag is deprecated,
+/// such as `he_IL.UTF-8` for `iw_IL.UTF-8`.
+fn check_deprecated_subtags(lang_code: &str) -> Option<String> {
+    let end = lang_code.find(['_', '.', '@']).unwrap_or(lang_code.len());
+    let (language, rest) = lang_code.split_at(end);
+
+    let mut locale = language.parse::<Locale>().ok()?;
+    LocaleCanonicalizer::new_common().canonicalize(&mut locale);
+
+    let canonical = locale.id.language.as_str();
+    (!canonical.eq_ignore_ascii_case(language)).then(|| [canonical, rest].concat())
+}
+
+/// Moves a search to the front of the history, keeping at most [`SEARCH_HISTORY_LIMIT`] entries.
+fn push_search_history(history: &mut Vec<String>, search: &str) {
+    let search = search.trim();
+
+    history.retain(|entry| !entry.eq_ignore_ascii_case(search));
+    history.insert(0, search.to_owned());
+    history.truncate(SEARCH_HISTORY_LIMIT);
+}
+
+/// Renders text with the segments matching a search query in the accent color.
+fn highlighted_text<'a>(text: &str, query: &str, selected: bool) -> Element<'a, Message> {
+    let segments = highlight_match(text, query);
+
+    widget::row::with_children(segments.into_iter().map(|(segment, is_match)| {
+        widget::text::body(segment)
+            .class(if is_match || selected {
+                cosmic::theme::Text::Accent
+            } else {
+                cosmic::theme::Text::Default
+            })
+            .into()
+    }))
+    .width(Length::Fill)
+    .into()
+}
+
+/// Splits text into `(segment, is_match)` pairs, where matches of the query are
+/// found case-insensitively.
+fn highlight_match(text: &str, query: &str) -> Vec<(String, bool)> {
+    let query: Vec<char> = query.trim().to_lowercase().chars().collect();
+
+    if query.is_empty() {
+        return vec![(text.to_owned(), false)];
+    }
+
+    let chars: Vec<(usize, char)> = text
+        .char_indices()
+        .map(|(pos, c)| (pos, c.to_lowercase().next().unwrap_or(c)))
+        .collect();
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+
+    while i + query.len() <= chars.len() {
+        let window = &chars[i..i + query.len()];
+
+        if !window.iter().map(|(_, c)| *c).eq(query.iter().copied()) {
+            i += 1;
+            continue;
+        }
+
+        let match_start = window[0].0;
+        let match_end = chars
+            .get(i + query.len())
+            .map_or(text.len(), |(pos, _)| *pos);
+
+        if start < match_start {
+            segments.push((text[start..match_start].to_owned(), false));
+        }
+
+        segments.push((text[match_start..match_end].to_owned(), true));
+        start = match_end;
+        i += query.len();
+    }
+
+    if start < text.len() {
+        segments.push((text[start..].to_owned(), false));
+    }
+
+    segments
+}
+
+fn localized_iso_codes(locale: &locale::Locale) -> (String, String) {
+    let ui_language = crate::localize::LANGUAGE_LOADER
+        .current_language()
+        .to_string();
+    let language = display_name_in_locale(locale, &ui_language);
+    let country = gettextrs::dgettext("iso_3166", &locale.territory.display_name);
+
+    (language, country)
+}
+
+/// The name of a locale's language as written in the target locale.
+///
+/// CLDR display names cover many minority languages and regional variants which
+/// are missing from the `iso_639` catalog, which remains the fallback.
+fn display_name_in_locale(locale_entry: &locale::Locale, target_locale: &str) -> String {
+    let mut language = language_display_name(&locale_entry.language.code, target_locale)
+        .unwrap_or_else(|| gettextrs::dgettext("iso_639", &locale_entry.language.display_name));
+
+    // Ensure language is title-cased.
+    let mut chars = language.chars();
+    if let Some(c) = chars.next() {
+        language = c.to_uppercase().collect::<String>() + chars.as_str();
+    }
+
+    language
+}
+
+thread_local! {
+    /// The CLDR language display names of the most recent target locale.
+    static LANGUAGE_DISPLAY_NAMES: RefCell<Option<(String, Option<LanguageDisplayNames>)>> =
+        const { RefCell::new(None) };
+}
+
+/// Looks up the CLDR display name of a language code in the target locale.
+///
+/// The display names are only built again when the target locale changes, as every
+/// language of a refresh is named in the same UI language.
+fn language_display_name(language: &str, target_locale: &str) -> Option<String> {
+    let language = language.parse::<Language>().ok()?;
+
+    LANGUAGE_DISPLAY_NAMES.with_borrow_mut(|cache| {
+        if cache
+            .as_ref()
+            .is_none_or(|(target, _)| target != target_locale)
+        {
+            let display_names =
+                super::shared_config::parse_locale(target_locale).and_then(|target| {
+                    LanguageDisplayNames::try_new(
+                        DisplayNamesPreferences::from(&target),
+                        DisplayNamesOptions::default(),
+                    )
+                    .ok()
+                });
+
+            *cache = Some((target_locale.to_owned(), display_names));
+        }
+
+        let (_, display_names) = cache.as_ref()?;
+        display_names.as_ref()?.of(language).map(str::to_owned)
+    })
+}
+
+fn localized_locale(locale: &locale::Locale, lang_code: String) -> SystemLocale {
+    let (language, country) = localized_iso_codes(locale);
+
+    SystemLocale {
+        lang_code,
+        display_name: format!("{language} ({country})"),
+        region_name: format!("{country} ({language})"),
+    }
+}
+
+fn popover_button(
+    id: usize,
+    expanded: bool,
+    recoverable: Option<u64>,
+) -> Element<'static, Message> {
+    let on_press = Message::ExpandLanguagePopover(if expanded { None } else { Some(id) });
+
+    let button = button::icon(widget::icon::from_name("view-more-symbolic"))
+        .extra_small()
+        .on_press(on_press);
+
+    if expanded {
+        widget::popover(button)
+            .position(widget::popover::Position::Bottom)
+            .popup(popover_menu(id, recoverable))
+            .on_close(Message::ExpandLanguagePopover(None))
+            .into()
+    } else {
+        button.into()
+    }
+}
+
+fn popover_menu(id: usize, recoverable: Option<u64>) -> Element<'static, Message> {
+    let remove = match recoverable {
+        Some(size) => fl!("language-pack", "remove", size = format_size(size)),
+        None => fl!("keyboard-sources", "remove"),
+    };
+
+    widget::column::with_children([
+        popover_menu_row(id, fl!("preferred-languages", "make-primary"), |id| {
+            SourceContext::SetPosition(id, 0)
+        }),
+        widget::divider::horizontal::default()
+            .apply(widget::container)
+            .padding([0, 8])
+            .into(),
+        popover_menu_row(id, fl!("keyboard-sources", "move-up"), |id| {
+            SourceContext::SetPosition(id, id.saturating_sub(1))
+        }),
+        widget::divider::horizontal::default()
+            .apply(widget::container)
+            .padding([0, 8])
+            .into(),
+        popover_menu_row(id, fl!("keyboard-sources", "move-down"), |id| {
+            SourceContext::SetPosition(id, id + 1)
+        }),
+        widget::divider::horizontal::default()
+            .apply(widget::container)
+            .padding([0, 8])
+            .into(),
+        popover_menu_row(id, remove, SourceContext::Remove),
+    ])
+    .width(Length::Fixed(200.0))
+    .apply(widget::container)
+    .padding(cosmic::theme::spacing().space_xxs)
+    .class(cosmic::theme::Container::Dropdown)
+    .into()
+}
+
+fn popover_menu_row(
+    id: usize,
+    label: String,
+    message: impl Fn(usize) -> SourceContext + 'static,
+) -> cosmic::Element<'static, Message> {
+    let spacing = cosmic::theme::spacing();
+    widget::text::body(label)
+        .align_y(Alignment::Center)
+        .apply(button::custom)
+        .padding([spacing.space_xxxs, spacing.space_xs])
+        .width(Length::Fill)
+        .class(cosmic::theme::Button::MenuItem)
+        .on_press(Message::SourceContext(message(id)))
+        .apply(Element::from)
+}
+
+/// Sets the system locale using D-Bus instead of localectl for OpenRC compatibility.
+pub async fn set_locale(
+    lang: String,
+    region: String,
+    login_screen: Option<DisplayManager>,
+) -> eyre::Result<()> {
+    tracing::debug!("setting locale lang={lang}, region={region}");
+
+    let old_lang = current_system_lang().await.unwrap_or_default();
+    let variables = build_locale_settings(&lang, &region);
+
+    match login_screen {
+        // Set both in one privileged step, so that only one authentication prompt is shown.
+        Some(display_manager) => {
+            configure_login_screen(display_manager, Some(&lang), &variables).await?;
+        }
+
+        None => {
+            apply_locale_variables(&variables).await?;
+            tracing::debug!("successfully set locale via D-Bus");
+        }
+    }
+
+    record_locale_change(LocaleChange {
+        timestamp: chrono::Utc::now(),
+        old_lang,
+        new_lang: lang.clone(),
+        new_region: region,
+    });
+
+    Ok(())
+}
+
+/// Sets the system language, keeping the regional formats of the system locale.
+pub async fn set_system_language(lang: String) -> eyre::Result<()> {
+    let region = match system_locale_variable("LC_TIME").await {
+        Some(region) => region,
+        None => lang.clone(),
+    };
+
+    set_locale(lang, region, None).await
+}
+
+/// The `LANG` variable of the system locale.
+pub(super) async fn current_system_lang() -> Option<String> {
+    system_locale_variable("LANG").await
+}
+
+/// A variable of the system locale, such as `LC_TIME`.
+async fn system_locale_variable(name: &str) -> Option<String> {
+    let conn = zbus::Connection::system().await.ok()?;
+
+    locale1::locale1Proxy::new(&conn)
+        .await
+        .ok()?
+        .locale()
+        .await
+        .ok()?
+        .into_iter()
+        .find_map(|variable| {
+            variable
+                .strip_prefix(name)?
+                .strip_prefix('=')
+                .map(str::to_owned)
+        })
+}
+
+/// The language of the current user's AccountsService account, as a `LANGUAGE` list.
+async fn current_user_language() -> Option<String> {
+    let conn = zbus::Connection::system().await.ok()?;
+    let uid = rustix::process::getuid().as_raw() as u64;
+
+    accounts_zbus::UserProxy::from_uid(&conn, uid)
+        .await
+        .ok()?
+        .language()
+        .await
+        .ok()
+}
+
+/// The other user accounts known to AccountsService, whether or not they are logged in.
+async fn list_other_users() -> Vec<UserInfo> {
+    let Ok(conn) = zbus::Connection::system().await else {
+        return Vec::new();
+    };
+
+    let Ok(accounts) = accounts_zbus::AccountsProxy::new(&conn).await else {
+        return Vec::new();
+    };
+
+    let uid = rustix::process::getuid().as_raw() as u64;
+    let mut users = Vec::new();
+
+    for path in accounts.list_cached_users().await.unwrap_or_default() {
+        let Ok(user) = accounts_zbus::UserProxy::new(&conn, path).await else {
+            continue;
+        };
+
+        let Ok(user_uid) = user.uid().await else {
+            continue;
+        };
+
+        if user_uid == uid {
+            continue;
+        }
+
+        users.push(UserInfo {
+            username: user.user_name().await.unwrap_or_default(),
+            real_name: user.real_name().await.unwrap_or_default(),
+        });
+    }
+
+    users
+}
+
+/// The language of a user's account, such as `de_DE.UTF-8` or `de_DE:en_US`, if one is set.
+async fn get_user_locale(username: &str) -> Option<String> {
+    let conn = zbus::Connection::system().await.ok()?;
+    let accounts = accounts_zbus::AccountsProxy::new(&conn).await.ok()?;
+    let path = accounts.find_user_by_name(username).await.ok()?;
+
+    accounts_zbus::UserProxy::new(&conn, path)
+        .await
+        .ok()?
+        .language()
+        .await
+        .ok()
+        .filter(|language| !language.is_empty())
+}
+
+/// The installed locales which match the languages of an AccountsService language list.
+fn locales_from_user_language<'a>(
+    language: &str,
+    available: impl Iterator<Item = &'a str> + Clone,
+) -> Vec<String> {
+    let mut locales: Vec<String> = Vec::new();
+
+    for entry in language.split(':').filter(|entry| !entry.is_empty()) {
+        let base = strip_locale_suffix(entry);
+        let found = available
+            .clone()
+            .find(|lang_code| *lang_code == entry)
+            .or_else(|| {
+                available
+                    .clone()
+                    .find(|lang_code| strip_locale_suffix(lang_code) == base)
+            });
+
+        if let Some(lang_code) = found
+            && !locales.iter().any(|locale| locale == lang_code)
+        {
+            locales.push(lang_code.to_owned());
+        }
+    }
+
+    locales
+}
+
+/// Compares the system locale with the language of the current user's account.
+async fn compare_system_and_user_locale() -> LocaleConsistency {
+    let (system, user) = futures::join!(current_system_lang(), current_user_language());
+    locale_consistency(system.as_deref(), user.as_deref())
+}
+
+/// Whether the primary language of a user's `LANGUAGE` list matches the system locale.
+/// Users without a language of their own follow the system locale.
+fn locale_consistency(system: Option<&str>, user: Option<&str>) -> LocaleConsistency {
+    let (Some(system), Some(user)) = (system, user) else {
+        return LocaleConsistency::Consistent;
+    };
+
+    let Some(user) = user.split(':').next().filter(|user| !user.is_empty()) else {
+        return LocaleConsistency::Consistent;
+    };
+
+    let system = strip_locale_suffix(system);
+    if strip_locale_suffix(user) == system {
+        return LocaleConsistency::Consistent;
+    }
+
+    LocaleConsistency::Diverged {
+        system,
+        user: user.to_owned(),
+    }
+}
+
+/// Saves a locale change to the front of the change history.
+fn record_locale_change(change: LocaleChange) {
+    let config = match cosmic_config::Config::new("com.system76.CosmicSettings", 1) {
+        Ok(config) => config,
+        Err(why) => {
+            tracing::error!(?why, "failed to open config to record locale change");
+            return;
+        }
+    };
+
+    let mut history: Vec<LocaleChange> = config.get(LOCALE_HISTORY_KEY).unwrap_or_default();
+    push_locale_change(&mut history, change);
+
+    if let Err(why) = config.set(LOCALE_HISTORY_KEY, &history) {
+        tracing::error!(?why, "failed to save locale change history");
+    }
+}
+
+/// Adds a change to the front of the history, dropping the oldest beyond the limit.
+fn push_locale_change(history: &mut Vec<LocaleChange>, change: LocaleChange) {
+    history.insert(0, change);
+    history.truncate(LOCALE_HISTORY_LIMIT);
+}
+
+/// The display manager which systemd starts, if it is one whose locale can be configured.
+async fn detect_display_manager() -> Option<DisplayManager> {
+    let unit = tokio::fs::read_link("/etc/systemd/system/display-manager.service")
+        .await
+        .ok()?;
+
+    display_manager_from_unit(unit.file_name()?.to_str()?)
+}
+
+fn display_manager_from_unit(unit: &str) -> Option<DisplayManager> {
+    match unit.strip_suffix(".service")? {
+        "gdm" | "gdm3" => Some(DisplayManager::Gdm),
+        "sddm" => Some(DisplayManager::Sddm),
+        _ => None,
+    }
+}
+
+/// The config file which sets the login screen locale of a display manager.
+fn login_screen_locale_path(display_manager: DisplayManager) -> &'static str {
+    match display_manager {
+        DisplayManager::Gdm => "/etc/systemd/system/display-manager.service.d/cosmic-locale.conf",
+        DisplayManager::Sddm => "/etc/sddm.conf.d/cosmic-locale.conf",
+    }
+}
+
+/// The contents of the config file which sets the login screen locale of a display manager.
+fn login_screen_locale_contents(display_manager: DisplayManager, lang: &str) -> String {
+    match display_manager {
+        DisplayManager::Gdm => format!("[Service]\nEnvironment=LANG={lang}\n"),
+        DisplayManager::Sddm => format!("[General]\nGreeterEnvironment=LANG={lang}\n"),
+    }
+}
+
+/// Formats Dockerfile instructions which generate the given locales and set them as `ENV`.
+fn docker_env_snippet(variables: &[(&str, &str)]) -> String {
+    if variables.is_empty() {
+        return String::new();
+    }
+
+    let mut generated: Vec<&str> = Vec::with_capacity(variables.len());
+    for &(_, lang_code) in variables {
+        if !generated.contains(&lang_code) {
+            generated.push(lang_code);
+        }
+    }
+
+    let assignments: Vec<String> = variables
+        .iter()
+        .map(|(var, lang_code)| format!("{var}={lang_code}"))
+        .collect();
+
+    format!(
+        "RUN locale-gen {}\nENV {}\n",
+        generated.join(" "),
+        assignments.join(" \\\n    ")
+    )
+}
+
+/// Formats Ansible tasks which generate the given locales and set the system locale variables.
+fn ansible_task(variables: &[(&str, &str)], locales: &[String]) -> String {
+    let mut generated: Vec<&str> = Vec::with_capacity(locales.len() + variables.len());
+    for lang_code in locales
+        .iter()
+        .map(String::as_str)
+        .chain(variables.iter().map(|(_, lang_code)| *lang_code))
+    {
+        if !generated.contains(&lang_code) {
+            generated.push(lang_code);
+        }
+    }
+
+    let mut yaml = String::from(
+        "- name: Generate locales\n  \
+         community.general.locale_gen:\n    \
+         name: \"{{ item }}\"\n    \
+         state: present\n  \
+         loop:\n",
+    );
+
+    for lang_code in generated {
+        yaml.push_str(&format!("    - \"{lang_code}\"\n"));
+    }
+
+    if !variables.is_empty() {
+        let assignments: Vec<String> = variables
+            .iter()
+            .map(|(var, lang_code)| format!("{var}={lang_code}"))
+            .collect();
+
+        yaml.push_str(&format!(
+            "\n- name: Set the system locale\n  \
+             ansible.builtin.command: localectl set-locale {}\n",
+            assignments.join(" ")
+        ));
+    }
+
+    yaml
+}
+
+/// Writes the login screen locale of a display manager with elevated privileges, or removes
+/// it when `lang` is `None`. Any given `KEY=VALUE` system locale variables are set with
+/// `localectl` in the same step.
+async fn configure_login_screen(
+    display_manager: DisplayManager,
+    lang: Option<&str>,
+    variables: &[String],
+) -> eyre::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let path = login_screen_locale_path(display_manager);
+
+    let mut script = String::new();
+    if !variables.is_empty() {
+        script.push_str("localectl set-locale \"$@\" && ");
+    }
+
+    // The path is passed as $0, followed by the locale variables.
+    script.push_str(if lang.is_some() {
+        "install -D -m 644 /dev/stdin \"$0\""
+    } else {
+        "rm -f \"$0\""
+    });
+
+    let mut child = tokio::process::Command::new("pkexec")
+        .args(["sh", "-c", &script, path])
+        .args(variables)
+        .stdin(std::process::Stdio::piped())
+        .spawn()
+        .wrap_err("failed to spawn pkexec")?;
+
+    if let Some(mut stdin) = child.stdin.take()
+        && let Some(lang) = lang
+    {
+        stdin
+            .write_all(login_screen_locale_contents(display_manager, lang).as_bytes())
+            .await
+            .wrap_err("failed to write login screen locale")?;
+    }
+
+    let status = child.wait().await.wrap_err("failed to wait for pkexec")?;
+    if !status.success() {
+        eyre::bail!("pkexec exited with {status}");
+    }
+
+    Ok(())
+}
+
+/// Sets the given `KEY=VALUE` locale variables via D-Bus, unsetting any others.
+async fn apply_locale_variables(variables: &[String]) -> eyre::Result<()> {
+    let conn = zbus::Connection::system()
+        .await
+        .wrap_err("failed to connect to system D-Bus")?;
+
+    let proxy = locale1::locale1Proxy::new(&conn)
+        .await
+        .wrap_err("failed to create locale1 D-Bus proxy")?;
+
+    let locale_strs: Vec<&str> = variables.iter().map(|s| s.as_str()).collect();
+
+    proxy
+        .set_locale(&locale_strs, true)
+        .await
+        .wrap_err("failed to set locale via D-Bus")
+}
+
+/// Unsets `LC_ALL` in the system locale while keeping every other variable.
+async fn remove_lc_all() -> eyre::Result<()> {
+    let conn = zbus::Connection::system()
+        .await
+        .wrap_err("failed to connect to system D-Bus")?;
+
+    let variables = locale1::locale1Proxy::new(&conn)
+        .await
+        .wrap_err("failed to create locale1 D-Bus proxy")?
+        .locale()
+        .await
+        .wrap_err("could not get locale from locale1")?;
+
+    apply_locale_variables(&without_lc_all(variables)).await
+}
+
+/// Removes the `LC_ALL` assignment from a list of locale variables.
+fn without_lc_all(variables: Vec<String>) -> Vec<String> {
+    variables
+        .into_iter()
+        .filter(|variable| !variable.starts_with("LC_ALL="))
+        .collect()
+}
+
+/// The variables which select the language of translations that are set, with their values,
+/// from the highest precedence to the lowest. Only the first is used; the others are overridden.
+fn compute_lookup_chain(system_locales: &BTreeMap<String, SystemLocale>) -> Vec<(String, String)> {
+    ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|variable| {
+            let locale = system_locales.get(variable)?;
+            Some((variable.to_owned(), locale.lang_code.clone()))
+        })
+        .collect()
+}
+
+/// The directory holding the gettext translation catalogs of installed applications.
+static GETTEXT_LOCALE_DIR: &str = "/usr/share/locale";
+
+/// The gettext catalogs of the system, by domain, with the locale directories holding them.
+async fn scan_translation_catalogs() -> BTreeMap<String, Vec<String>> {
+    let mut catalogs = BTreeMap::<String, Vec<String>>::new();
+
+    let Ok(mut locale_dirs) = tokio::fs::read_dir(GETTEXT_LOCALE_DIR).await else {
+        return catalogs;
+    };
+
+    while let Ok(Some(locale_dir)) = locale_dirs.next_entry().await {
+        let name = locale_dir.file_name().to_string_lossy().into_owned();
+
+        let Ok(mut files) = tokio::fs::read_dir(locale_dir.path().join("LC_MESSAGES")).await else {
+            continue;
+        };
+
+        while let Ok(Some(file)) = files.next_entry().await {
+            let path = file.path();
+            if path.extension().is_none_or(|extension| extension != "mo") {
+                continue;
+            }
+
+            if let Some(domain) = path.file_stem() {
+                catalogs
+                    .entry(domain.to_string_lossy().into_owned())
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+    }
+
+    catalogs
+}
+
+/// Whether each translation catalog covers `locale`.
+fn query_app_locale_support(
+    catalogs: &BTreeMap<String, Vec<String>>,
+    locale: &str,
+) -> Vec<AppLocaleSupportInfo> {
+    let lookup_dirs = gettext_lookup_dirs(locale);
+
+    catalogs
+        .iter()
+        .map(|(domain, locales)| AppLocaleSupportInfo {
+            app_name: domain.clone(),
+            supported: locales.iter().any(|locale| lookup_dirs.contains(locale)),
+        })
+        .collect()
+}
+
+/// The directories which gettext searches for translations of a locale, most specific first.
+fn gettext_lookup_dirs(locale: &str) -> Vec<String> {
+    let (locale, modifier) = match locale.split_once('@') {
+        Some((locale, modifier)) => (locale, Some(modifier)),
+        None => (locale, None),
+    };
+    let locale = locale.split('.').next().unwrap_or(locale);
+    let language = locale.split('_').next().unwrap_or(locale);
+
+    let mut dirs = Vec::with_capacity(4);
+    for base in [locale, language] {
+        let candidates = modifier
+            .map(|modifier| format!("{base}@{modifier}"))
+            .into_iter()
+            .chain(std::iter::once(base.to_owned()));
+
+        for dir in candidates {
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+    }
+
+    dirs
+}
+
+/// Shell startup files, relative to the home directory, which may set locale variables.
+static SHELL_STARTUP_FILES: &[&str] = &[
+    ".bashrc",
+    ".bash_profile",
+    ".zshrc",
+    ".profile",
+    ".config/fish/config.fish",
+];
+
+/// Locale variables assigned by the user's shell startup files.
+async fn find_shell_locale_overrides() -> Vec<ShellLocaleOverride> {
+    let Some(home) = dirs::home_dir() else {
+        return Vec::new();
+    };
+
+    let mut overrides = Vec::new();
+
+    for file in SHELL_STARTUP_FILES {
+        let path = home.join(file);
+        if let Ok(contents) = tokio::fs::read_to_string(&path).await {
+            overrides.extend(parse_shell_locale_overrides(&path, &contents));
+        }
+    }
+
+    overrides
+}
+
+/// Finds assignments of locale variables, such as `export LANG=de_DE.UTF-8` in POSIX shells
+/// or `set -gx LANG de_DE.UTF-8` in fish.
+fn parse_shell_locale_overrides(file: &Path, contents: &str) -> Vec<ShellLocaleOverride> {
+    let is_locale_variable =
+        |name: &str| name == "LANG" || name == "LANGUAGE" || name.starts_with("LC_");
+
+    contents
+        .lines()
+        .enumerate()
+        .filter_map(|(line, text)| {
+            let text = text.trim();
+            if text.starts_with('#') {
+                return None;
+            }
+
+            let (variable, value) = if let Some(rest) = text.strip_prefix("set ") {
+                let mut words = rest
+                    .split_whitespace()
+                    .skip_while(|word| word.starts_with('-'));
+                let variable = words.next()?;
+                (variable, words.next()?)
+            } else {
+                let text = text.strip_prefix("export ").unwrap_or(text).trim_start();
+                text.split_once('=')?
+            };
+
+            if !is_locale_variable(variable) {
+                return None;
+            }
+
+            let value = value.split_whitespace().next().unwrap_or_default();
+
+            Some(ShellLocaleOverride {
+                file: file.to_path_buf(),
+                line: line + 1,
+                variable: variable.to_owned(),
+                value: value.trim_matches(['"', '\'']).to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Exports the locale variables to the systemd and D-Bus activation environments of the
+/// session, which Flatpak apps and their portals are started from.
+async fn sync_session_locale(lang: &str, region: &str) -> Result<(), String> {
+    let output = tokio::process::Command::new("dbus-update-activation-environment")
+        .arg("--systemd")
+        .args(build_locale_settings(lang, region))
+        .output()
+        .await
+        .map_err(|why| why.to_string())?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_owned())
+    }
+}
+
+/// Sets the user's preferred language list via AccountsService D-Bus.
+/// This updates the LANGUAGE environment variable for gettext-based applications.
+/// The language_list should be a colon-separated string like "de_DE:de:en_US:en".
+pub async fn set_user_language(language_list: String) -> eyre::Result<()> {
+    let conn = zbus::Connection::system()
+        .await
+        .wrap_err("zbus system connection error")?;
+
+    let uid = rustix::process::getuid().as_raw() as u64;
+
+    let user_proxy = accounts_zbus::UserProxy::from_uid(&conn, uid)
+        .await
+        .wrap_err("failed to create AccountsService user proxy")?;
+
+    user_proxy
+        .set_language(&language_list)
+        .await
+        .wrap_err("failed to set language via AccountsService")?;
+
+    tracing::debug!("set user language via AccountsService: {language_list}");
+    Ok(())
+}
+
+/// The separators and signs which a locale uses when formatting numbers and lists.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocaleSeparators {
+    pub decimal: String,
+    /// Empty when the locale does not group digits.
+    pub thousands: String,
+    /// The separator between the first items of a list, such as `, `.
+    pub list: String,
+    pub plus_sign: String,
+    pub minus_sign: String,
+}
+
+/// Shows a separator with its whitespace made visible, or a dash if there is none.
+fn visible_separator(separator: String) -> String {
+    if separator.is_empty() {
+        return String::from("–");
+    }
+
+    separator
+        .chars()
+        .map(|c| if c.is_whitespace() { '␣' } else { c })
+        .collect()
+}
+
+/// The separators of a locale string, found by formatting known inputs.
+fn locale_separators_for_region(locale: &str) -> LocaleSeparators {
+    let sign = |value: Decimal| {
+        let formatted = super::shared_config::parse_locale(locale)
+            .and_then(|locale| DecimalFormatter::try_new((&locale).into(), Default::default()).ok())
+            .map(|formatter| formatter.format(&value).to_string())
+            .unwrap_or_else(|| value.to_string());
+
+        formatted
+            .chars()
+            .filter(|c| !c.is_numeric())
+            .collect::<String>()
+    };
+
+    let list = super::shared_config::parse_locale(locale)
+        .and_then(|locale| {
+            ListFormatter::try_new_and((&locale).into(), ListFormatterOptions::default()).ok()
+        })
+        .and_then(|formatter| {
+            let formatted = formatter.format_to_string(["0", "1", "2"].into_iter());
+            let start = formatted.find('0')? + 1;
+            let end = formatted.find('1')?;
+            formatted.get(start..end).map(str::to_owned)
+        })
+        .unwrap_or_else(|| String::from(", "));
+
+    LocaleSeparators {
+        decimal: decimal_separator_for_locale(locale).to_string(),
+        thousands: grouping_separator_for_locale(locale)
+            .map(String::from)
+            .unwrap_or_default(),
+        list,
+        plus_sign: sign(Decimal::from(1).with_sign_display(SignDisplay::Always)),
+        minus_sign: sign(Decimal::from(-1)),
+    }
+}
+
+/// The current local date and time, for the live formatting preview.
+fn local_datetime(now: &jiff::Zoned) -> Option<DateTime<Gregorian>> {
+    Some(DateTime {
+        date: Date::try_new_gregorian(i32::from(now.year()), now.month() as u8, now.day() as u8)
+            .ok()?,
+        time: Time::try_new(now.hour() as u8, now.minute() as u8, now.second() as u8, 0).ok()?,
+    })
+}
+
+/// The abbreviation of a time's timezone at that time, such as `EDT`.
+fn timezone_abbreviation(now: &jiff::Zoned) -> String {
+    let info = now.time_zone().to_offset_info(now.timestamp());
+    let abbreviation = info.abbreviation();
+
+    // Zones without an abbreviation use their offset, such as `+03`.
+    if abbreviation.starts_with(['+', '-']) {
+        format!("UTC{abbreviation}")
+    } else {
+        abbreviation.to_owned()
+    }
+}
+
+/// Formats a date with the fields and length of an ICU skeleton, such as `yMMMMd`.
+///
+/// Skeletons select which fields are shown and how long they are, while the locale
+/// still decides their order and punctuation.
+fn date_skeleton_formatter(
+    prefs: DateTimeFormatterPreferences,
+    skeleton: &str,
+) -> Option<DateTimeFormatter<DateFieldSet>> {
+    let count = |symbols: &[char]| skeleton.chars().filter(|c| symbols.contains(c)).count();
+
+    let years = count(&['y']);
+    let months = count(&['M', 'L']);
+    let days = count(&['d']);
+    let weekdays = count(&['E']);
+
+    if years + months + days + weekdays != skeleton.chars().count() {
+        return None;
+    }
+
+    let date_fields = match (years > 0, months > 0, days > 0, weekdays > 0) {
+        (false, false, true, false) => DateFields::D,
+        (false, true, true, false) => DateFields::MD,
+        (true, true, true, false) => DateFields::YMD,
+        (false, false, true, true) => DateFields::DE,
+        (false, true, true, true) => DateFields::MDE,
+        (true, true, true, true) => DateFields::YMDE,
+        (false, false, false, true) => DateFields::E,
+        (false, true, false, false) => DateFields::M,
+        (true, true, false, false) => DateFields::YM,
+        (true, false, false, false) => DateFields::Y,
+        _ => return None,
+    };
+
+    let mut builder = FieldSetBuilder::default();
+    builder.date_fields = Some(date_fields);
+    builder.length = Some(if months >= 4 || weekdays >= 4 {
+        datetime_options::Length::Long
+    } else if months == 3 || weekdays > 0 {
+        datetime_options::Length::Medium
+    } else {
+        datetime_options::Length::Short
+    });
+
+    // `yy` asks for a two-digit year, any other count for the full year.
+    if years > 0 {
+        builder.year_style = Some(if years == 2 {
+            YearStyle::Auto
+        } else {
+            YearStyle::Full
+        });
+    }
+
+    let field_set = builder.build_date().ok()?;
+
+    DateTimeFormatter::try_new(prefs, field_set).ok()
+}
+
+/// Whether a locale string formats numbers with digits other than ASCII digits, such as
+/// the Eastern Arabic digits of `fa_IR`.
+fn uses_non_ascii_digits(locale: &str) -> bool {
+    let Some(locale) = super::shared_config::parse_locale(locale) else {
+        return false;
+    };
+
+    let Ok(formatter) = DecimalFormatter::try_new((&locale).into(), Default::default()) else {
+        return false;
+    };
+
+    !formatter
+        .format(&Decimal::from(1_234_567_890))
+        .to_string()
+        .contains(|c: char| c.is_ascii_digit())
+}
+
+/// The decimal separator of a locale string, found by formatting `1.5`.
+fn decimal_separator_for_locale(locale: &str) -> char {
+    let mut value = Decimal::from(15);
+    value.multiply_pow10(-1);
+
+    first_separator(locale, &value).unwrap_or('.')
+}
+
+/// The thousands separator of a locale string, found by formatting `1234567`.
+fn grouping_separator_for_locale(locale: &str) -> Option<char> {
+    first_separator(locale, &Decimal::from(1_234_567))
+}
+
+/// The first character of a formatted number which is not a digit.
+fn first_separator(locale: &str, value: &Decimal) -> Option<char> {
+    let locale = super::shared_config::parse_locale(locale)?;
+    let formatter = DecimalFormatter::try_new((&locale).into(), Default::default()).ok()?;
+
+    formatter
+        .format(value)
+        .to_string()
+        .chars()
+        .find(|c| !c.is_numeric())
+}
+
+/// The ISO 4217 currency of a locale, from the `int_curr_symbol` of its glibc locale data.
+async fn locale_currency(locale: &str) -> Option<CurrencyCode> {
+    let output = tokio::process::Command::new("locale")
+        .arg("int_curr_symbol")
+        .env("LC_ALL", locale)
+        .output()
+        .await
+        .inspect_err(|why| tracing::error!(?why, "failed to read the currency of {locale}"))
+        .ok()?;
+
+    parse_currency_code(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses an `int_curr_symbol` value, such as `EUR ` for `de_DE.UTF-8`.
+fn parse_currency_code(int_curr_symbol: &str) -> Option<CurrencyCode> {
+    let code = int_curr_symbol.trim();
+
+    if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+        return None;
+    }
+
+    tinystr::TinyAsciiStr::try_from_str(code)
+        .ok()
+        .map(CurrencyCode)
+}
+
+/// Formats an amount with the CLDR currency pattern and symbol of the locale, such as `1.234,56 €`.
+fn format_currency(locale: &Locale, currency: CurrencyCode, value: &Decimal) -> Option<String> {
+    let formatter = CurrencyFormatter::try_new(locale.into(), Default::default()).ok()?;
+
+    Some(formatter.format_fixed_decimal(value, currency).to_string())
+}
+
+/// The `postal_fmt` used by glibc locales that do not define their own.
+static DEFAULT_POSTAL_FORMAT: &str = "%f%N%a%N%d%N%b%N%h %s %e %r%N%z %T%N%c%N";
+
+/// The direction of the script of a locale string, which is inferred from the language and
+/// territory when the locale does not name a script.
+fn locale_text_direction(lang_code: &str) -> TextDirection {
+    let direction = super::shared_config::parse_locale(&strip_locale_suffix(lang_code))
+        .and_then(|locale| LocaleDirectionality::new_extended().get(&locale.id));
+
+    match direction {
+        Some(Direction::RightToLeft) => TextDirection::RightToLeft,
+        _ => TextDirection::LeftToRight,
+    }
+}
+
+/// Territories whose `LC_NAME` `name_fmt` places the family name before the given name.
+static FAMILY_NAME_FIRST_TERRITORIES: &[&str] = &["CN", "HK", "JP", "KR", "MO", "TW"];
+
+/// Whether ICU has formatting data for the locale, rather than falling back to the root locale.
+///
+/// ICU's compiled data falls back to the root locale instead of failing, so this compares
+/// the locale's date format with the root locale's `1776 M07 4`.
+fn icu_has_locale_data(locale: &Locale) -> bool {
+    if locale.id.language.is_unknown() {
+        return true;
+    }
+
+    let format = |locale: &Locale| {
+        DateTimeFormatter::try_new(locale.into(), fieldsets::YMD::medium())
+            .ok()
+            .map(|dtf| {
+                dtf.format(&Date::try_new_gregorian(1776, 7, 4).unwrap())
+                    .to_string()
+            })
+    };
+
+    format(locale) != format(&Locale::UNKNOWN)
+}
+
+/// Splits a number into a significand in tenths and an exponent which is a multiple of three,
+/// such as `(12, 6)` for `1_200_000`.
+fn engineering_notation(value: i64) -> (i64, i32) {
+    let magnitude = value.unsigned_abs();
+    let mut exponent = 0;
+
+    while exponent + 3 < 19 && magnitude / 10u64.pow(exponent + 3) > 0 {
+        exponent += 3;
+    }
+
+    let tenths = if exponent == 0 {
+        magnitude * 10
+    } else {
+        let divisor = 10u64.pow(exponent - 1);
+        (magnitude + divisor / 2) / divisor
+    };
+
+    let tenths = tenths as i64;
+    (if value < 0 { -tenths } else { tenths }, exponent as i32)
+}
+
+/// Writes an exponent with superscript digits, such as `⁶` or `⁻¹²`.
+fn superscript(exponent: i32) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    let digits = exponent
+        .unsigned_abs()
+        .to_string()
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|digit| DIGITS[digit as usize]);
+
+    if exponent < 0 {
+        std::iter::once('⁻').chain(digits).collect()
+    } else {
+        digits.collect()
+    }
+}
+
+/// Formats a number with the percent pattern of the locale, such as `12,34 %` in German.
+fn format_percent(locale: &Locale, value: &Decimal) -> Option<String> {
+    let formatter = PercentFormatter::try_new(locale.into(), Default::default()).ok()?;
+
+    Some(formatter.format(value).to_string())
+}
+
+/// The suffix of an ordinal number in the given ordinal plural category of a language.
+fn ordinal_suffix(language: &str, category: PluralCategory) -> Option<&'static str> {
+    use PluralCategory::{Few, One, Other, Two};
+
+    let suffix = match (language, category) {
+        ("en", One) => "st",
+        ("en", Two) => "nd",
+        ("en", Few) => "rd",
+        ("en", _) => "th",
+        ("fr", One) => "er",
+        ("fr", _) => "e",
+        ("ca", One) => "r",
+        ("ca", Two) => "n",
+        ("ca", Few) => "t",
+        ("ca", _) => "è",
+        ("sv", One) => ":a",
+        ("sv", _) => ":e",
+        ("es" | "it" | "pt", Other) => "º",
+        ("nl", Other) => "e",
+        ("ru", Other) => "-й",
+        ("cs" | "da" | "de" | "fi" | "hu" | "nb" | "nn" | "pl" | "sk" | "tr", Other) => ".",
+        _ => return None,
+    };
+
+    Some(suffix)
+}
+
+/// Formats a duration with the long unit names of the locale, such as
+/// `2 hours, 30 minutes, 15 seconds`.
+fn format_duration(locale: &Locale, duration: &icu_duration::Duration) -> Option<String> {
+    let options = ValidatedDurationFormatterOptions::validate(DurationFormatterOptions {
+        base: BaseStyle::Long,
+        ..Default::default()
+    })
+    .ok()?;
+
+    let formatter = DurationFormatter::try_new(locale.into(), options).ok()?;
+
+    Some(formatter.format(duration).to_string())
+}
+
+/// Removes the hour and minutes from a formatted time, leaving its day period, such as `PM`.
+fn extract_day_period(formatted: &str) -> String {
+    let is_time = |c: char| c.is_ascii_digit() || c == ':' || c == '.';
+
+    formatted
+        .split_whitespace()
+        .filter_map(|token| {
+            let mut token = token;
+
+            // Strip a leading or trailing time, such as in `上午1:00`.
+            let start = token.trim_start_matches(is_time);
+            if token[..token.len() - start.len()].contains(|c: char| c.is_ascii_digit()) {
+                token = start;
+            }
+
+            let end = token.trim_end_matches(is_time);
+            if token[end.len()..].contains(|c: char| c.is_ascii_digit()) {
+                token = end;
+            }
+
+            (!token.is_empty()).then_some(token)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Detects the order of the date components from a numeric date formatted in the locale.
+fn date_order_for_locale(locale: &Locale) -> Option<DateOrder> {
+    let dtf = DateTimeFormatter::try_new(
+        locale.into(),
+        fieldsets::YMD::short().with_year_style(YearStyle::Full),
+    )
+    .ok()?;
+
+    let formatted = dtf
+        .format(&Date::try_new_gregorian(1999, 11, 22).unwrap())
+        .to_string();
+
+    // Locales with non-Latin digits are not detected.
+    let year = formatted.find("1999")?;
+    let month = formatted.find("11")?;
+    let day = formatted.find("22")?;
+
+    if year < month && month < day {
+        Some(DateOrder::YearMonthDay)
+    } else if day < month && month < year {
+        Some(DateOrder::DayMonthYear)
+    } else if month < day && day < year {
+        Some(DateOrder::MonthDayYear)
+    } else {
+        None
+    }
+}
+
+/// The calendar systems of a locale's region which ICU can compute, beginning with the
+/// locale's default.
+fn supported_calendars_for_locale(locale: &str) -> Vec<&'static str> {
+    let Some(locale) = super::shared_config::parse_locale(locale) else {
+        return vec!["gregorian"];
+    };
+
+    // Calendars which depend on astronomical observation, such as `islamic`, have no kind.
+    let mut calendars = regional_calendars(&locale)
+        .iter()
+        .copied()
+        .filter(|id| calendar_kind(id).is_some())
+        .collect::<Vec<_>>();
+
+    if let Ok(default) = AnyCalendarKind::try_new(CalendarPreferences::from(&locale))
+        && let Some(position) = calendars
+            .iter()
+            .position(|id| calendar_kind(id) == Some(default))
+    {
+        let default = calendars.remove(position);
+        calendars.insert(0, default);
+    }
+
+    calendars
+}
+
+/// The calendar systems of a locale's region, in order of preference.
+fn regional_calendars(locale: &Locale) -> &'static [&'static str] {
+    let region = locale.id.region.as_ref().map(|region| region.as_str());
+
+    REGIONAL_CALENDARS
+        .iter()
+        .find(|(regions, _)| region.is_some_and(|region| regions.contains(&region)))
+        .map_or(&["gregorian"][..], |(_, calendars)| *calendars)
+}
+
+/// The calendar algorithm of a CLDR calendar identifier, such as `islamic-umalqura`.
+fn calendar_algorithm(id: &str) -> Option<CalendarAlgorithm> {
+    let value = icu::locale::extensions::unicode::Value::try_from_str(id).ok()?;
+    CalendarAlgorithm::try_from(&value).ok()
+}
+
+fn calendar_kind(id: &str) -> Option<AnyCalendarKind> {
+    AnyCalendarKind::try_from(calendar_algorithm(id)?).ok()
+}
+
+fn calendar_system_label(id: &str) -> String {
+    match id {
+        "buddhist" => fl!("calendar-system", "buddhist"),
+        "chinese" => fl!("calendar-system", "chinese"),
+        "coptic" => fl!("calendar-system", "coptic"),
+        "dangi" => fl!("calendar-system", "dangi"),
+        "ethiopic" => fl!("calendar-system", "ethiopic"),
+        "gregorian" => fl!("calendar-system", "gregorian"),
+        "hebrew" => fl!("calendar-system", "hebrew"),
+        "indian" => fl!("calendar-system", "indian"),
+        "islamic-civil" => fl!("calendar-system", "islamic-civil"),
+        "islamic-tbla" => fl!("calendar-system", "islamic-tbla"),
+        "islamic-umalqura" => fl!("calendar-system", "islamic-umalqura"),
+        "japanese" => fl!("calendar-system", "japanese"),
+        "persian" => fl!("calendar-system", "persian"),
+        "roc" => fl!("calendar-system", "roc"),
+        _ => id.to_owned(),
+    }
+}
+
+/// The preferred calendar of a locale's region after the Gregorian calendar, where CLDR
+/// defaults to the Gregorian calendar but the region keeps using another.
+fn native_calendar_algorithm(locale: &Locale) -> Option<CalendarAlgorithm> {
+    let default = AnyCalendarKind::try_new(CalendarPreferences::from(locale)).ok()?;
+    if default != AnyCalendarKind::Gregorian {
+        return None;
+    }
+
+    regional_calendars(locale)
+        .iter()
+        .filter(|id| **id != "gregorian" && calendar_kind(id).is_some())
+        .find_map(|id| calendar_algorithm(id))
+}
+
+/// Returns the territory of a locale string.
+/// "de_DE.UTF-8" -> "DE"
+/// "sr_RS.UTF-8@latin" -> "RS"
+fn territory_code(locale: &str) -> Option<&str> {
+    let locale = locale.split(['.', '@']).next()?;
+    let (_, territory) = locale.split_once('_')?;
+    (!territory.is_empty()).then_some(territory)
+}
+
+/// Formats a fixed example address according to a glibc `postal_fmt` string.
+/// Lines are joined with commas so that the address fits on a single row.
+fn format_postal_address(format: &str) -> String {
+    let mut output = String::new();
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('N') => output.push('\n'),
+            Some('t') => output.push(' '),
+            Some('h') => output.push_str("42"),
+            Some('s') => output.push_str("Main Street"),
+            Some('T') => output.push_str("Springfield"),
+            Some('S') => output.push_str("State"),
+            Some('z') => output.push_str("12345"),
+            Some('%') => output.push('%'),
+            // Fields which the example address does not use, such as the
+            // care-of, firm, department, building, floor, room, and country.
+            _ => (),
+        }
+    }
+
+    output
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .map(|line| line.trim_matches(',').trim().to_owned())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Builds a colon-separated language list for the LANGUAGE environment variable.
+/// Converts locales like ["de_DE.UTF-8", "en_US.UTF-8"] to "de_DE:de:en_US:en".
+///
+/// Important: The list stops at English locales since English is typically the
+/// source language and doesn't need translation files. This prevents fallback
+/// to other languages when English is selected.
+fn build_language_list(locales: &[String]) -> String {
+    let mut parts = Vec::new();
+
+    for locale in locales {
+        // Parse locale: language_TERRITORY[.CODESET][@MODIFIER]
+        // We want to extract "language_TERRITORY" without codeset or modifier
+        let base = strip_locale_suffix(locale);
+
+        // Get the language-only code (e.g., "de" from "de_DE")
+        let lang = base.split('_').next().unwrap_or(&base);
+
+        // Add the full locale code (e.g., "de_DE")
+        parts.push(base.clone());
+
+        // Add the language-only code as fallback if different
+        if lang != base {
+            parts.push(lang.to_string());
+        }
+
+        // Stop after English - it's the source language and needs no translation
+        // This matches gnome-language-selector's behavior
+        if lang == "en" {
+            break;
+        }
+    }
+
+    parts.join(":")
+}
+
+/// Strips the codeset (.UTF-8) and modifier (@latin) from a locale string.
+/// "de_DE.UTF-8" -> "de_DE"
+/// "sr_RS@latin" -> "sr_RS"
+/// "sr_RS.UTF-8@latin" -> "sr_RS"
+fn strip_locale_suffix(locale: &str) -> String {
+    // First strip the codeset (everything from '.' onwards)
+    let without_codeset = locale.split('.').next().unwrap_or(locale);
+    // Then strip the modifier (everything from '@' onwards)
+    without_codeset
+        .split('@')
+        .next()
+        .unwrap_or(without_codeset)
+        .to_string()
+}
+
+/// Builds the locale settings array for D-Bus SetLocale call.
+/// Sets LANG to the language parameter and all LC_* variables to the region parameter.
+fn build_locale_settings(lang: &str, region: &str) -> Vec<String> {
+    vec![
+        format!("LANG={}", lang),
+        format!("LC_ADDRESS={}", region),
+        format!("LC_IDENTIFICATION={}", region),
+        format!("LC_MEASUREMENT={}", region),
+        format!("LC_MONETARY={}", region),
+        format!("LC_NAME={}", region),
+        format!("LC_NUMERIC={}", region),
+        format!("LC_PAPER={}", region),
+        format!("LC_TELEPHONE={}", region),
+        format!("LC_TIME={}", region),
+    ]
+}
+
+/// CLI commands for inspecting and changing the system locale without the settings window.
+pub mod commands {
+    /// Print the locales which can be selected, as their code and display name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the locales cannot be read from the system.
+    pub fn list_locales() -> color_eyre::Result<()> {
+        let refresh = block_on(super::page_reload())??;
+
+        for language in refresh.available_languages.values() {
+            println!("{}\t{}", language.lang_code, language.display_name);
+        }
+
+        Ok(())
+    }
+
+    /// Print the system locale variables, such as `LANG=de_DE.UTF-8`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the locale cannot be read from systemd-localed.
+    pub fn get_locale() -> color_eyre::Result<()> {
+        let refresh = block_on(super::page_reload())??;
+
+        for (variable, locale) in &refresh.system_locales {
+            println!("{variable}={}", locale.lang_code);
+        }
+
+        Ok(())
+    }
+
+    /// Set the system language and regional formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if systemd-localed rejects the locale.
+    pub fn set_locale(lang: &str, region: &str) -> color_eyre::Result<()> {
+        block_on(super::set_locale(lang.to_owned(), region.to_owned(), None))?
+    }
+
+    fn block_on<F: Future>(future: F) -> color_eyre::Result<F::Output> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(runtime.block_on(future))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn system_locale(lang_code: &str) -> SystemLocale {
+        SystemLocale {
+            lang_code: lang_code.to_owned(),
+            display_name: String::new(),
+            region_name: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_locale_settings_includes_all_lc_variables() {
+        let lang = "en_US.UTF-8";
+        let region = "de_DE.UTF-8";
+        let settings = build_locale_settings(lang, region);
+
+        assert_eq!(settings.len(), 10);
+        assert!(settings.contains(&format!("LANG={}", lang)));
+        assert!(settings.contains(&format!("LC_ADDRESS={}", region)));
+        assert!(settings.contains(&format!("LC_IDENTIFICATION={}", region)));
+        assert!(settings.contains(&format!("LC_MEASUREMENT={}", region)));
+        assert!(settings.contains(&format!("LC_MONETARY={}", region)));
+        assert!(settings.contains(&format!("LC_NAME={}", region)));
+        assert!(settings.contains(&format!("LC_NUMERIC={}", region)));
+        assert!(settings.contains(&format!("LC_PAPER={}", region)));
+        assert!(settings.contains(&format!("LC_TELEPHONE={}", region)));
+        assert!(settings.contains(&format!("LC_TIME={}", region)));
+    }
+
+    #[test]
+    fn test_build_locale_settings_uses_correct_values() {
+        let lang = "fr_FR.UTF-8";
+        let region = "en_GB.UTF-8";
+        let settings = build_locale_settings(lang, region);
+
+        // LANG should use the lang parameter
+        assert!(settings.iter().any(|s| s == "LANG=fr_FR.UTF-8"));
+        // LC_* variables should use the region parameter
+        assert!(settings.iter().any(|s| s == "LC_TIME=en_GB.UTF-8"));
+    }
+
+    #[test]
+    fn test_territory_code_strips_codeset_and_modifier() {
+        assert_eq!(territory_code("de_DE.UTF-8"), Some("DE"));
+        assert_eq!(territory_code("sr_RS.UTF-8@latin"), Some("RS"));
+        assert_eq!(territory_code("en_US"), Some("US"));
+        assert_eq!(territory_code("eo.UTF-8"), None);
+    }
+
+    #[test]
+    fn test_format_postal_address_follows_territory_order() {
+        let us = POSTAL_FORMATS
+            .iter()
+            .find(|(code, _)| *code == "US")
+            .unwrap()
+            .1;
+        let de = POSTAL_FORMATS
+            .iter()
+            .find(|(code, _)| *code == "DE")
+            .unwrap()
+            .1;
+
+        assert_eq!(
+            format_postal_address(us),
+            "42 Main Street, Springfield, State 12345"
+        );
+        assert_eq!(
+            format_postal_address(de),
+            "Main Street 42, 12345 Springfield"
+        );
+    }
+
+    #[test]
+    fn test_locale_text_direction_follows_script() {
+        let rtl = |lang_code| locale_text_direction(lang_code) == TextDirection::RightToLeft;
+
+        assert!(rtl("ar_EG.UTF-8"));
+        assert!(rtl("he_IL.utf8"));
+        assert!(rtl("ur_PK"));
+        assert!(rtl("dv_MV.UTF-8"));
+        assert!(!rtl("en_US.UTF-8"));
+        assert!(!rtl("arn_CL.UTF-8"));
+        assert!(!rtl("sr_RS.UTF-8@latin"));
+    }
+
+    #[test]
+    fn test_parse_locale_conf_skips_comments_and_quotes() {
+        let persisted = parse_locale_conf(
+            "# Written by systemd-localed\nLANG=de_DE.UTF-8\n\nLC_TIME=\"en_GB.UTF-8\"\n",
+        );
+
+        assert_eq!(persisted.len(), 2);
+        assert_eq!(persisted["LANG"], "de_DE.UTF-8");
+        assert_eq!(persisted["LC_TIME"], "en_GB.UTF-8");
+    }
+
+    #[test]
+    fn test_parse_iso6709() {
+        let approx = |coordinates: &str, expected: (f64, f64)| {
+            let (latitude, longitude) = parse_iso6709(coordinates).unwrap();
+            assert!(
+                (latitude - expected.0).abs() < 1e-9,
+                "{coordinates}: {latitude}"
+            );
+            assert!(
+                (longitude - expected.1).abs() < 1e-9,
+                "{coordinates}: {longitude}"
+            );
+        };
+
+        approx("+4042-07400", (40.7, -74.0));
+        approx("+513030-0000731", (51.508_333_333, -0.125_277_777_8));
+        assert_eq!(parse_iso6709(""), None);
+        assert_eq!(parse_iso6709("4042-07400"), None);
+    }
+
+    #[test]
+    fn test_nearest_country() {
+        let zone_tab = "# comment\n\
+            DE\t+5230+01322\tEurope/Berlin\n\
+            FR\t+4852+00220\tEurope/Paris\n\
+            JP\t+353916+1394441\tAsia/Tokyo\n";
+
+        assert_eq!(nearest_country(zone_tab, 48.1, 11.6), Some("DE"));
+        assert_eq!(nearest_country(zone_tab, 45.8, 4.8), Some("FR"));
+        assert_eq!(nearest_country(zone_tab, 34.7, 135.5), Some("JP"));
+        assert_eq!(nearest_country("", 0.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_move_locale() {
+        let mut locales: Vec<String> = ["en_US", "de_DE", "fr_FR", "ja_JP"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert!(move_locale(&mut locales, 3, 0));
+        assert_eq!(locales, ["ja_JP", "en_US", "de_DE", "fr_FR"]);
+
+        assert!(move_locale(&mut locales, 0, 2));
+        assert_eq!(locales, ["en_US", "de_DE", "ja_JP", "fr_FR"]);
+
+        assert!(move_locale(&mut locales, 1, 1));
+        assert_eq!(locales, ["en_US", "de_DE", "ja_JP", "fr_FR"]);
+    }
+
+    #[test]
+    fn test_move_locale_out_of_range() {
+        let mut locales: Vec<String> = ["en_US", "de_DE"].into_iter().map(String::from).collect();
+
+        assert!(!move_locale(&mut locales, 2, 0));
+        assert!(!move_locale(&mut locales, 0, 2));
+        assert!(!move_locale(&mut Vec::new(), 0, 0));
+        assert_eq!(locales, ["en_US", "de_DE"]);
+    }
+
+    #[test]
+    fn test_locale_completeness() {
+        let mut system_locales = BTreeMap::new();
+        system_locales.insert("LANG".to_owned(), system_locale("en_US.UTF-8"));
+        assert_eq!(locale_completeness(&system_locales), 0);
+
+        system_locales.insert("LC_TIME".to_owned(), system_locale("en_GB.UTF-8"));
+        system_locales.insert("LC_NUMERIC".to_owned(), system_locale("en_GB.UTF-8"));
+        system_locales.insert("LC_MONETARY".to_owned(), system_locale("en_GB.UTF-8"));
+        assert_eq!(locale_completeness(&system_locales), 33);
+
+        for category in LOCALE_CATEGORIES {
+            system_locales.insert((*category).to_owned(), system_locale("en_GB.UTF-8"));
+        }
+        assert_eq!(locale_completeness(&system_locales), 100);
+    }
+
+    #[test]
+    fn test_supported_calendars_for_locale() {
+        assert_eq!(supported_calendars_for_locale("en_US.UTF-8"), ["gregorian"]);
+        assert_eq!(
+            supported_calendars_for_locale("th_TH.UTF-8"),
+            ["buddhist", "gregorian"]
+        );
+        assert_eq!(
+            supported_calendars_for_locale("he_IL.UTF-8"),
+            ["gregorian", "hebrew", "islamic-civil", "islamic-tbla"]
+        );
+    }
+
+    #[test]
+    fn test_writing_direction_for_locale() {
+        assert_eq!(
+            writing_direction_for_locale("de_DE.UTF-8"),
+            WritingDirection::LeftToRight
+        );
+        assert_eq!(
+            writing_direction_for_locale("ar_EG.UTF-8"),
+            WritingDirection::RightToLeft
+        );
+        assert_eq!(
+            writing_direction_for_locale("ja_JP.UTF-8"),
+            WritingDirection::LeftToRight
+        );
+    }
+
+    #[test]
+    fn test_locale_matches_writing_direction() {
+        let matches = |lang_code| {
+            WritingDirection::ALL
+                .into_iter()
+                .filter(|direction| locale_matches_writing_direction(lang_code, *direction))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matches("de_DE.UTF-8"), [WritingDirection::LeftToRight]);
+        assert_eq!(matches("he_IL.UTF-8"), [WritingDirection::RightToLeft]);
+        assert_eq!(
+            matches("zh_TW.UTF-8"),
+            [WritingDirection::LeftToRight, WritingDirection::TopToBottom]
+        );
+        assert_eq!(
+            matches("ja_JP.UTF-8"),
+            [WritingDirection::LeftToRight, WritingDirection::TopToBottom]
+        );
+    }
+
+    #[test]
+    fn test_weekend_days_for_locale() {
+        assert_eq!(
+            weekend_days_for_locale("en_US.UTF-8"),
+            [Weekday::Saturday, Weekday::Sunday]
+        );
+        assert_eq!(
+            weekend_days_for_locale("ar_SA.UTF-8"),
+            [Weekday::Friday, Weekday::Saturday]
+        );
+        assert_eq!(weekend_days_for_locale("hi_IN.UTF-8"), [Weekday::Sunday]);
+    }
+
+    #[test]
+    fn test_gettext_lookup_dirs() {
+        assert_eq!(gettext_lookup_dirs("de_DE.UTF-8"), ["de_DE", "de"]);
+        assert_eq!(gettext_lookup_dirs("de"), ["de"]);
+        assert_eq!(
+            gettext_lookup_dirs("sr_RS.UTF-8@latin"),
+            ["sr_RS@latin", "sr_RS", "sr@latin", "sr"]
+        );
+    }
+
+    #[test]
+    fn test_query_app_locale_support() {
+        let catalogs = BTreeMap::from([
+            (
+                String::from("coreutils"),
+                vec![String::from("de"), String::from("fr")],
+            ),
+            (String::from("grep"), vec![String::from("fr")]),
+        ]);
+
+        assert_eq!(
+            query_app_locale_support(&catalogs, "de_DE.UTF-8"),
+            [
+                AppLocaleSupportInfo {
+                    app_name: String::from("coreutils"),
+                    supported: true,
+                },
+                AppLocaleSupportInfo {
+                    app_name: String::from("grep"),
+                    supported: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_locales_from_user_language() {
+        let available = ["de_DE.UTF-8", "en_US.UTF-8", "fr_FR.UTF-8"];
+
+        assert_eq!(
+            locales_from_user_language("de_DE:en_US.UTF-8:de:ja_JP", available.into_iter()),
+            ["de_DE.UTF-8", "en_US.UTF-8"]
+        );
+        assert!(locales_from_user_language("ja_JP.UTF-8", available.into_iter()).is_empty());
+    }
+
+    #[test]
+    fn test_compute_lookup_chain() {
+        let mut system_locales = BTreeMap::new();
+        assert!(compute_lookup_chain(&system_locales).is_empty());
+
+        system_locales.insert("LANG".to_owned(), system_locale("de_DE.UTF-8"));
+        system_locales.insert("LC_TIME".to_owned(), system_locale("en_GB.UTF-8"));
+        system_locales.insert("LC_MESSAGES".to_owned(), system_locale("en_US.UTF-8"));
+        system_locales.insert("LANGUAGE".to_owned(), system_locale("de_AT:de"));
+
+        assert_eq!(
+            compute_lookup_chain(&system_locales),
+            [
+                (String::from("LANGUAGE"), String::from("de_AT:de")),
+                (String::from("LC_MESSAGES"), String::from("en_US.UTF-8")),
+                (String::from("LANG"), String::from("de_DE.UTF-8")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_locale_differs_from_persisted() {
+        let persisted = parse_locale_conf("LANG=de_DE.UTF-8\nLC_TIME=en_GB.UTF-8");
+
+        let mut system_locales = BTreeMap::new();
+        system_locales.insert("LANG".to_owned(), system_locale("de_DE.UTF-8"));
+        system_locales.insert("LC_TIME".to_owned(), system_locale("en_GB.UTF-8"));
+        assert!(!locale_differs_from_persisted(&persisted, &system_locales));
+
+        system_locales.insert("LC_TIME".to_owned(), system_locale("de_DE.UTF-8"));
+        assert!(locale_differs_from_persisted(&persisted, &system_locales));
+
+        system_locales.insert("LC_TIME".to_owned(), system_locale("en_GB.UTF-8"));
+        system_locales.insert("LC_NUMERIC".to_owned(), system_locale("en_GB.UTF-8"));
+        assert!(locale_differs_from_persisted(&persisted, &system_locales));
+    }
+
+    #[test]
+    fn test_native_calendar_algorithm_only_overrides_gregorian_defaults() {
+        let algorithm = |locale: &str| native_calendar_algorithm(&locale.parse().unwrap());
+
+        assert_eq!(algorithm("ja-JP"), Some(CalendarAlgorithm::Japanese));
+        assert_eq!(algorithm("am-ET"), Some(CalendarAlgorithm::Ethiopic));
+        assert_eq!(algorithm("ar-EG"), Some(CalendarAlgorithm::Coptic));
+        assert_eq!(algorithm("en-US"), None);
+        assert_eq!(algorithm("fa-IR"), None);
+    }
+
+    #[test]
+    fn test_keyboard_layout_mismatch() {
+        assert!(keyboard_layout_mismatch("us", "ja_JP.UTF-8"));
+        assert!(keyboard_layout_mismatch("de", "fr_FR.UTF-8"));
+        assert!(!keyboard_layout_mismatch("us", "en_GB.UTF-8"));
+        assert!(!keyboard_layout_mismatch("ch", "de_DE.UTF-8"));
+        assert!(!keyboard_layout_mismatch("us", "eo.UTF-8"));
+        assert!(!keyboard_layout_mismatch("dvorak", "ja_JP.UTF-8"));
+    }
+
+    #[test]
+    fn test_push_locale_change() {
+        let change = |n: usize| LocaleChange {
+            timestamp: chrono::DateTime::UNIX_EPOCH,
+            old_lang: String::from("en_US.UTF-8"),
+            new_lang: format!("{n}"),
+            new_region: String::from("en_US.UTF-8"),
+        };
+
+        let mut history = Vec::new();
+        for n in 0..LOCALE_HISTORY_LIMIT + 5 {
+            push_locale_change(&mut history, change(n));
+        }
+
+        assert_eq!(history.len(), LOCALE_HISTORY_LIMIT);
+        assert_eq!(history[0], change(LOCALE_HISTORY_LIMIT + 4));
+        assert_eq!(history[LOCALE_HISTORY_LIMIT - 1], change(5));
+    }
+
+    #[test]
+    fn test_display_manager_from_unit() {
+        assert_eq!(
+            display_manager_from_unit("gdm3.service"),
+            Some(DisplayManager::Gdm)
+        );
+        assert_eq!(
+            display_manager_from_unit("sddm.service"),
+            Some(DisplayManager::Sddm)
+        );
+        assert_eq!(display_manager_from_unit("cosmic-greeter.service"), None);
+        assert_eq!(display_manager_from_unit("gdm"), None);
+    }
+
+    #[test]
+    fn test_date_skeleton_formatter() {
+        let locale: Locale = "en-US".parse().unwrap();
+        let datetime = DateTime {
+            date: Date::try_new_gregorian(1776, 7, 4).unwrap(),
+            time: Time::try_new(12, 0, 0, 0).unwrap(),
+        };
+
+        let format = |skeleton| {
+            date_skeleton_formatter((&locale).into(), skeleton)
+                .map(|dtf| dtf.format(&datetime).to_string())
+        };
+
+        let long = format("yMMMMd").unwrap();
+        assert!(long.contains("July") && long.contains("1776"));
+
+        let month_day = format("MMMd").unwrap();
+        assert!(month_day.contains("Jul") && !month_day.contains("1776"));
+
+        assert_eq!(format("yMd hh:mm"), None);
+        assert_eq!(format("yd"), None);
+    }
+
+    #[test]
+    fn test_uses_non_ascii_digits() {
+        assert!(!uses_non_ascii_digits("en_US.UTF-8"));
+        assert!(!uses_non_ascii_digits("de_DE.UTF-8"));
+        assert!(uses_non_ascii_digits("fa_IR.UTF-8"));
+        assert!(!uses_non_ascii_digits("invalid locale"));
+    }
+
+    #[test]
+    fn test_number_separators_for_locale() {
+        assert_eq!(decimal_separator_for_locale("en_US.UTF-8"), '.');
+        assert_eq!(decimal_separator_for_locale("de_DE.UTF-8"), ',');
+        assert_eq!(decimal_separator_for_locale("invalid locale"), '.');
+        assert_eq!(grouping_separator_for_locale("en_US.UTF-8"), Some(','));
+        assert_eq!(grouping_separator_for_locale("de_DE.UTF-8"), Some('.'));
+    }
+
+    #[test]
+    fn test_locale_separators_for_region() {
+        assert_eq!(
+            locale_separators_for_region("en_US.UTF-8"),
+            LocaleSeparators {
+                decimal: String::from("."),
+                thousands: String::from(","),
+                list: String::from(", "),
+                plus_sign: String::from("+"),
+                minus_sign: String::from("-"),
+            }
+        );
+
+        let german = locale_separators_for_region("de_DE.UTF-8");
+        assert_eq!(german.decimal, ",");
+        assert_eq!(german.thousands, ".");
+        assert_eq!(german.list, ", ");
+    }
+
+    #[test]
+    fn test_locale_change_requires_relogin() {
+        assert!(!locale_change_requires_relogin(
+            "en_US.UTF-8",
+            "en_GB.UTF-8"
+        ));
+        assert!(locale_change_requires_relogin("en_US.UTF-8", "de_DE.UTF-8"));
+        assert!(locale_change_requires_relogin("zh_CN.UTF-8", "zh_TW.UTF-8"));
+        assert!(locale_change_requires_relogin(
+            "sr_RS.UTF-8",
+            "sr_RS.UTF-8@latin"
+        ));
+        assert!(!locale_change_requires_relogin("C.UTF-8", "C.UTF-8"));
+    }
+
+    #[test]
+    fn test_locale_consistency() {
+        assert_eq!(
+            locale_consistency(Some("de_DE.UTF-8"), Some("de_DE:de")),
+            LocaleConsistency::Consistent
+        );
+        assert_eq!(
+            locale_consistency(Some("de_DE.UTF-8"), Some("")),
+            LocaleConsistency::Consistent
+        );
+        assert_eq!(
+            locale_consistency(None, Some("fr_FR:fr")),
+            LocaleConsistency::Consistent
+        );
+        assert_eq!(
+            locale_consistency(Some("de_DE.UTF-8"), Some("fr_FR:fr:en_US:en")),
+            LocaleConsistency::Diverged {
+                system: String::from("de_DE"),
+                user: String::from("fr_FR"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_locale_script() {
+        assert_eq!(locale_script("de_DE.UTF-8"), LocaleScript::Latin);
+        assert_eq!(locale_script("ru_RU.UTF-8"), LocaleScript::Cyrillic);
+        assert_eq!(locale_script("sr_RS.UTF-8@latin"), LocaleScript::Latin);
+        assert_eq!(locale_script("sr_RS.UTF-8"), LocaleScript::Cyrillic);
+        assert_eq!(locale_script("ar_EG.UTF-8"), LocaleScript::Arabic);
+        assert_eq!(locale_script("ja_JP.UTF-8"), LocaleScript::Cjk);
+        assert_eq!(locale_script("ko_KR.UTF-8"), LocaleScript::Cjk);
+        assert_eq!(locale_script("zh_TW.UTF-8"), LocaleScript::Cjk);
+        assert_eq!(locale_script("hi_IN.UTF-8"), LocaleScript::Devanagari);
+        assert_eq!(locale_script("el_GR.UTF-8"), LocaleScript::Greek);
+        assert_eq!(locale_script("he_IL.UTF-8"), LocaleScript::Hebrew);
+        assert_eq!(locale_script("th_TH.UTF-8"), LocaleScript::Thai);
+        assert_eq!(locale_script("ka_GE.UTF-8"), LocaleScript::Other);
+        assert_eq!(locale_script("C.UTF-8"), LocaleScript::Other);
+    }
+
+    #[test]
+    fn test_continent_for_locale() {
+        assert_eq!(continent_for_locale("de_DE.UTF-8"), Some(Continent::Europe));
+        assert_eq!(
+            continent_for_locale("pt_BR.UTF-8"),
+            Some(Continent::Americas)
+        );
+        assert_eq!(continent_for_locale("ja_JP.UTF-8"), Some(Continent::Asia));
+        assert_eq!(continent_for_locale("sw_KE"), Some(Continent::Africa));
+        assert_eq!(
+            continent_for_locale("mi_NZ.UTF-8"),
+            Some(Continent::Oceania)
+        );
+        assert_eq!(continent_for_locale("eo.UTF-8"), None);
+    }
+
+    #[test]
+    fn test_territory_continents_are_sorted() {
+        assert!(
+            TERRITORY_CONTINENTS
+                .windows(2)
+                .all(|pair| pair[0].0 < pair[1].0)
+        );
+    }
+
+    #[test]
+    fn test_check_deprecated_subtags() {
+        assert_eq!(
+            check_deprecated_subtags("iw_IL.UTF-8"),
+            Some(String::from("he_IL.UTF-8"))
+        );
+        assert_eq!(
+            check_deprecated_subtags("in_ID"),
+            Some(String::from("id_ID"))
+        );
+        assert_eq!(check_deprecated_subtags("ji"), Some(String::from("yi")));
+        assert_eq!(check_deprecated_subtags("he_IL.UTF-8"), None);
+        assert_eq!(check_deprecated_subtags("en_US.UTF-8"), None);
+        assert_eq!(check_deprecated_subtags("POSIX"), None);
+    }
+
+    #[test]
+    fn test_parse_currency_code() {
+        assert_eq!(
+            parse_currency_code("EUR \n").map(|currency| currency.0.to_string()),
+            Some(String::from("EUR"))
+        );
+        assert_eq!(
+            parse_currency_code("USD ").map(|currency| currency.0.to_string()),
+            Some(String::from("USD"))
+        );
+        // The C and POSIX locales define no currency.
+        assert!(parse_currency_code("\n").is_none());
+        assert!(parse_currency_code("eur").is_none());
+    }
+
+    #[test]
+    fn test_format_currency_follows_locale_patterns() {
+        let mut value = Decimal::from(123_456);
+        value.multiply_pow10(-2);
+
+        let format = |locale: &str, code: &str| {
+            let currency = parse_currency_code(code)?;
+            format_currency(&locale.parse().unwrap(), currency, &value)
+        };
+
+        assert_eq!(format("en-US", "USD").as_deref(), Some("$1,234.56"));
+        assert_eq!(format("de-DE", "EUR").as_deref(), Some("1.234,56\u{a0}€"));
+    }
+
+    #[test]
+    fn test_ordinal_suffix() {
+        let ordinals = |locale: &str| {
+            let locale = locale.parse::<Locale>().unwrap();
+            let rules =
+                PluralRules::try_new_ordinal(PluralRulesPreferences::from(&locale)).unwrap();
+
+            (1..=4_usize)
+                .map(|n| ordinal_suffix(locale.id.language.as_str(), rules.category_for(n)))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ordinals("en-US"),
+            [Some("st"), Some("nd"), Some("rd"), Some("th")]
+        );
+        assert_eq!(
+            ordinals("fr-FR"),
+            [Some("er"), Some("e"), Some("e"), Some("e")]
+        );
+        assert_eq!(ordinals("de-DE"), [Some("."); 4]);
+        assert_eq!(ordinals("ja-JP"), [None; 4]);
+    }
+
+    #[test]
+    fn test_format_duration_uses_locale_unit_names() {
+        let duration = icu_duration::Duration {
+            hours: 2,
+            minutes: 30,
+            seconds: 15,
+            ..Default::default()
+        };
+
+        let format = |locale: &str| format_duration(&locale.parse().unwrap(), &duration);
+
+        assert_eq!(
+            format("en-US").as_deref(),
+            Some("2 hours, 30 minutes, 15 seconds")
+        );
+        assert!(format("de-DE").is_some_and(|formatted| formatted.contains("Stunden")));
+        assert!(format("pl-PL").is_some_and(|formatted| formatted.contains("godziny")));
+    }
+
+    #[test]
+    fn test_format_percent_follows_locale_patterns() {
+        let mut value = Decimal::from(1234);
+        value.multiply_pow10(-2);
+
+        let percent = |locale: &str| format_percent(&locale.parse().unwrap(), &value).unwrap();
+
+        assert_eq!(percent("en-US"), "12.34%");
+        assert_eq!(percent("de-DE"), "12,34\u{a0}%");
+        assert_eq!(percent("tr-TR"), "%12,34");
+    }
+
+    #[test]
+    fn test_highlight_match_splits_matching_segments() {
+        assert_eq!(
+            highlight_match("English (United Kingdom)", "ng"),
+            [
+                ("E".to_owned(), false),
+                ("ng".to_owned(), true),
+                ("lish (United Ki".to_owned(), false),
+                ("ng".to_owned(), true),
+                ("dom)".to_owned(), false),
+            ]
+        );
+
+        assert_eq!(
+            highlight_match("Deutsch (Österreich)", "öst"),
+            [
+                ("Deutsch (".to_owned(), false),
+                ("Öst".to_owned(), true),
+                ("erreich)".to_owned(), false),
+            ]
+        );
+
+        assert_eq!(
+            highlight_match("Français", ""),
+            [("Français".to_owned(), false)]
+        );
+    }
+
+    #[test]
+    fn test_date_order_for_locale() {
+        let order = |locale: &str| date_order_for_locale(&locale.parse().unwrap());
+
+        assert_eq!(order("en-US"), Some(DateOrder::MonthDayYear));
+        assert_eq!(order("de-DE"), Some(DateOrder::DayMonthYear));
+        assert_eq!(order("ja-JP"), Some(DateOrder::YearMonthDay));
+    }
+
+    #[test]
+    fn test_extract_day_period_removes_time() {
+        assert_eq!(extract_day_period("1:00\u{202f}PM"), "PM");
+        assert_eq!(extract_day_period("1:00 a. m."), "a. m.");
+        assert_eq!(extract_day_period("上午1:00"), "上午");
+        assert_eq!(extract_day_period("오후 1:00"), "오후");
+    }
+
+    #[test]
+    fn test_superscript_exponent() {
+        assert_eq!(superscript(6), "⁶");
+        assert_eq!(superscript(0), "⁰");
+        assert_eq!(superscript(-12), "⁻¹²");
+    }
+
+    #[test]
+    fn test_icu_has_locale_data() {
+        assert!(icu_has_locale_data(&"en-US".parse().unwrap()));
+        assert!(icu_has_locale_data(&"de-DE".parse().unwrap()));
+        assert!(!icu_has_locale_data(&"tlh".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_engineering_notation() {
+        assert_eq!(engineering_notation(1_200_000), (12, 6));
+        assert_eq!(engineering_notation(1_250), (13, 3));
+        assert_eq!(engineering_notation(-45_600), (-456, 3));
+        assert_eq!(engineering_notation(999), (9990, 0));
+    }
+
+    #[test]
+    fn test_bidi_sample_text() {
+        assert!(bidi_sample_text("ar_EG.UTF-8").is_some());
+        assert!(bidi_sample_text("he_IL").is_some());
+        assert_eq!(bidi_sample_text("en_US.UTF-8"), None);
+        assert_eq!(bidi_sample_text("hu_HU.UTF-8"), None);
+    }
+
+    #[test]
+    fn test_sample_text_for_locale() {
+        assert_eq!(
+            sample_text_for_locale("de_DE.UTF-8"),
+            "Victor jagt zwölf Boxkämpfer quer über den großen Sylter Deich."
+        );
+        assert_eq!(
+            sample_text_for_locale("zh_TW.UTF-8"),
+            "我能吞下玻璃而不傷身體。"
+        );
+        assert_eq!(
+            sample_text_for_locale("zh_CN.UTF-8"),
+            "我能吞下玻璃而不伤身体。"
+        );
+        assert_eq!(sample_text_for_locale("xx_XX.UTF-8"), DEFAULT_SAMPLE_TEXT);
+    }
+
+    #[test]
+    fn test_language_display_name() {
+        assert_eq!(
+            language_display_name("de", "fr_FR.UTF-8").as_deref(),
+            Some("allemand")
+        );
+        assert_eq!(
+            language_display_name("fr", "de_DE.UTF-8").as_deref(),
+            Some("Französisch")
+        );
+        assert_eq!(language_display_name("not a code", "en_US.UTF-8"), None);
+        assert_eq!(language_display_name("de", "not a locale"), None);
+    }
+
+    #[test]
+    fn test_docker_env_snippet() {
+        assert_eq!(docker_env_snippet(&[]), "");
+        assert_eq!(
+            docker_env_snippet(&[
+                ("LANG", "de_DE.UTF-8"),
+                ("LC_NUMERIC", "de_DE.UTF-8"),
+                ("LC_TIME", "en_GB.UTF-8"),
+            ]),
+            "RUN locale-gen de_DE.UTF-8 en_GB.UTF-8
+ENV LANG=de_DE.UTF-8 \\
+    LC_NUMERIC=de_DE.UTF-8 \\
+    LC_TIME=en_GB.UTF-8
+"
+        );
+    }
+
+    #[test]
+    fn test_ansible_task() {
+        let locales = vec![String::from("de_DE.UTF-8"), String::from("en_US.UTF-8")];
+
+        assert_eq!(
+            ansible_task(
+                &[("LANG", "de_DE.UTF-8"), ("LC_TIME", "en_GB.UTF-8")],
+                &locales
+            ),
+            "- name: Generate locales
+  community.general.locale_gen:
+    name: \"{{ item }}\"
+    state: present
+  loop:
+    - \"de_DE.UTF-8\"
+    - \"en_US.UTF-8\"
+    - \"en_GB.UTF-8\"
+
+- name: Set the system locale
+  ansible.builtin.command: localectl set-locale LANG=de_DE.UTF-8 LC_TIME=en_GB.UTF-8
+"
+        );
+    }
+
+    #[test]
+    fn test_recommended_input_method() {
+        assert_eq!(
+            recommended_input_method("ja_JP.UTF-8").map(|info| info.engine),
+            Some(String::from("ibus-mozc"))
+        );
+        assert_eq!(
+            recommended_input_method("zh_TW.UTF-8").map(|info| info.package),
+            Some(String::from("ibus-chewing"))
+        );
+        assert_eq!(
+            recommended_input_method("zh_CN.UTF-8").map(|info| info.package),
+            Some(String::from("ibus-libpinyin"))
+        );
+        assert_eq!(recommended_input_method("de_DE.UTF-8"), None);
+    }
+
+    #[test]
+    fn test_font_requirements() {
+        assert_eq!(
+            font_requirements("ja_JP.UTF-8"),
+            [("ja", "NotoSansCJK", "fonts-noto-cjk")]
+        );
+        assert_eq!(
+            font_requirements("ar_EG.UTF-8"),
+            [("ar", "NotoSansArabic", "fonts-noto-core")]
+        );
+        assert!(font_requirements("de_DE.UTF-8").is_empty());
+        assert!(LOCALE_FONTS.is_sorted_by_key(|(code, ..)| *code));
+    }
+
+    #[test]
+    fn test_parse_package_size() {
+        assert_eq!(parse_package_size("2048\n", 1024), Some(2_097_152));
+        assert_eq!(parse_package_size("1536", 1), Some(1536));
+        assert_eq!(parse_package_size("", 1024), None);
+        assert_eq!(parse_package_size("(none)", 1), None);
+    }
+
+    #[test]
+    fn test_rpm_langpack_name() {
+        assert_eq!(rpm_langpack_name("de_DE.UTF-8"), "glibc-langpack-de");
+        assert_eq!(rpm_langpack_name("sr_RS.UTF-8@latin"), "glibc-langpack-sr");
+    }
+
+    #[test]
+    fn test_extract_charset() {
+        assert_eq!(extract_charset("de_DE.UTF-8"), "UTF-8");
+        assert_eq!(extract_charset("de_DE.ISO-8859-1@euro"), "ISO-8859-1");
+        assert_eq!(extract_charset("sr_RS@latin"), "");
+        assert_eq!(extract_charset("C"), "");
+        assert!(is_utf8_charset("utf8"));
+        assert!(!is_utf8_charset("ISO-8859-15"));
+    }
+
+    #[test]
+    fn test_without_lc_all() {
+        assert_eq!(
+            without_lc_all(vec![
+                String::from("LANG=de_DE.UTF-8"),
+                String::from("LC_ALL=C.UTF-8"),
+                String::from("LC_TIME=en_GB.UTF-8"),
+            ]),
+            [
+                String::from("LANG=de_DE.UTF-8"),
+                String::from("LC_TIME=en_GB.UTF-8"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_shell_locale_overrides() {
+        let path = Path::new("/home/user/.bashrc");
+        let contents = "# export LANG=C\nexport LANG=\"de_DE.UTF-8\"\nLC_TIME=en_GB.UTF-8\nPATH=$HOME/bin:$PATH\nset -gx LC_ALL fr_FR.UTF-8\n";
+
+        assert_eq!(
+            parse_shell_locale_overrides(path, contents),
+            [
+                ShellLocaleOverride {
+                    file: path.to_path_buf(),
+                    line: 2,
+                    variable: String::from("LANG"),
+                    value: String::from("de_DE.UTF-8"),
+                },
+                ShellLocaleOverride {
+                    file: path.to_path_buf(),
+                    line: 3,
+                    variable: String::from("LC_TIME"),
+                    value: String::from("en_GB.UTF-8"),
+                },
+                ShellLocaleOverride {
+                    file: path.to_path_buf(),
+                    line: 5,
+                    variable: String::from("LC_ALL"),
+                    value: String::from("fr_FR.UTF-8"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_language_pack_name() {
+        assert_eq!(language_pack_name("de_DE.UTF-8"), "language-pack-de");
+        assert_eq!(language_pack_name("sr_RS.UTF-8@latin"), "language-pack-sr");
+        assert_eq!(language_pack_name("zh_CN.UTF-8"), "language-pack-zh-hans");
+        assert_eq!(language_pack_name("zh_TW.UTF-8"), "language-pack-zh-hant");
+    }
+
+    #[test]
+    fn test_apt_candidate_available() {
+        assert!(apt_candidate_available(
+            "language-pack-en:\n  Installed: (none)\n  Candidate: 1:24.04+20240817\n"
+        ));
+        assert!(!apt_candidate_available(
+            "language-pack-en:\n  Installed: (none)\n  Candidate: (none)\n"
+        ));
+        // Packages unknown to apt print nothing.
+        assert!(!apt_candidate_available(""));
+    }
+
+    #[test]
+    fn test_parse_apt_status() {
+        assert_eq!(
+            parse_apt_status("pmstatus:language-pack-de:42.5:Installing language-pack-de"),
+            Some(42.5)
+        );
+        assert_eq!(
+            parse_apt_status("dlstatus:1:10:Retrieving file 1 of 2"),
+            Some(10.0)
+        );
+        assert_eq!(parse_apt_status("Reading package lists..."), None);
+    }
+
+    #[test]
+    fn test_push_search_history_dedups_and_caps() {
+        let mut history = Vec::new();
+
+        for search in ["deu", "fra", " Deu "] {
+            push_search_history(&mut history, search);
+        }
+
+        assert_eq!(history, ["Deu", "fra"]);
+
+        for i in 0..SEARCH_HISTORY_LIMIT {
+            push_search_history(&mut history, &i.to_string());
+        }
+
+        assert_eq!(history.len(), SEARCH_HISTORY_LIMIT);
+        assert_eq!(history[0], (SEARCH_HISTORY_LIMIT - 1).to_string());
+    }
+
+    #[test]
+    fn test_normalize_codeset_matches_locale_a_output() {
+        assert_eq!(normalize_codeset("de_DE.UTF-8"), "de_DE.utf8");
+        assert_eq!(normalize_codeset("de_DE.utf8"), "de_DE.utf8");
+        assert_eq!(
+            normalize_codeset("ca_ES.UTF-8@valencia"),
+            "ca_ES.utf8@valencia"
+        );
+        assert_eq!(normalize_codeset("en_US"), "en_US");
+    }
+}
@@ -0,0 +1,450 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Time applet settings which are shared between the date and region pages.
+
+use cosmic_config::{ConfigGet, ConfigSet};
+use icu::calendar::types::Weekday;
+use icu::calendar::week;
+use icu::datetime::input::{Date, DateTime, Time};
+use icu::datetime::{DateTimeFormatter, DateTimeFormatterPreferences, fieldsets};
+use icu::locale::Locale;
+#[cfg(feature = "page-date")]
+use icu_experimental::displaynames::RegionDisplayNames;
+use icu_experimental::displaynames::{DisplayNamesOptions, LanguageDisplayNames};
+use regex::Regex;
+
+pub const TIME_APPLET_CONFIG: &str = "com.system76.CosmicAppletTime";
+pub const TIME_APPLET_CONFIG_VERSION: u64 = 1;
+
+pub const MILITARY_TIME: &str = "military_time";
+pub const FIRST_DAY_OF_WEEK: &str = "first_day_of_week";
+pub const TIME_FORMAT_OVERRIDE: &str = "time_format_override";
+
+/// Whether times follow the locale's clock or always use a 12 or 24-hour clock.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum TimeFormatOverride {
+    #[default]
+    FollowLocale,
+    Force12h,
+    Force24h,
+}
+
+impl TimeFormatOverride {
+    /// Whether to use a 24-hour clock, asking the locale only when following it.
+    pub fn is_24h(self, locale_default: impl FnOnce() -> bool) -> bool {
+        match self {
+            Self::FollowLocale => locale_default(),
+            Self::Force12h => false,
+            Self::Force24h => true,
+        }
+    }
+}
+
+/// Resets the time applet's locale-derived settings to the defaults of a region.
+#[cfg(feature = "page-region")]
+pub fn apply_region_defaults(region: &str) {
+    let config = match cosmic_config::Config::new(TIME_APPLET_CONFIG, TIME_APPLET_CONFIG_VERSION) {
+        Ok(config) => config,
+        Err(err) => {
+            tracing::error!(
+                ?err,
+                "Failed to create cosmic applet config for time settings"
+            );
+            return;
+        }
+    };
+
+    let time_format = config.get(TIME_FORMAT_OVERRIDE).unwrap_or_default();
+    write_region_defaults(&config, region, time_format);
+}
+
+#[cfg(feature = "page-region")]
+fn write_region_defaults(config: &impl ConfigSet, region: &str, time_format: TimeFormatOverride) {
+    let locale = parse_locale(region);
+
+    // Update military_time based on new locale, unless a clock is forced
+    let new_military_time = time_format.is_24h(|| locale.as_ref().is_some_and(default_24h));
+    if let Err(why) = config.set(MILITARY_TIME, new_military_time) {
+        tracing::error!(?why, "Failed to update military_time after region change");
+    }
+
+    // Update first_day_of_week based on new locale
+    let new_first_day = locale.as_ref().map_or(6, default_first_day);
+    if let Err(why) = config.set(FIRST_DAY_OF_WEEK, new_first_day) {
+        tracing::error!(
+            ?why,
+            "Failed to update first_day_of_week after region change"
+        );
+    }
+}
+
+/// Converts a POSIX locale string such as `de_DE.UTF-8` into an ICU locale.
+pub fn parse_locale(locale: &str) -> Option<Locale> {
+    locale
+        .split('.')
+        .next()?
+        .replacen('_', "-", 1)
+        .parse::<Locale>()
+        .ok()
+}
+
+/// The name of a POSIX locale's language in the language itself, such as `Deutsch` for
+/// `de_DE.UTF-8`.
+#[cfg(feature = "page-region")]
+pub fn language_autonym(locale: &str) -> Option<String> {
+    let locale = parse_locale(locale)?;
+    let display_names =
+        LanguageDisplayNames::try_new((&locale).into(), DisplayNamesOptions::default()).ok()?;
+    let autonym = display_names.of(locale.id.language)?;
+
+    // Languages such as Spanish do not capitalize their own name in running text.
+    let mut chars = autonym.chars();
+    let first = chars.next()?;
+
+    Some(first.to_uppercase().chain(chars).collect())
+}
+
+/// Whether the locale displays time with a 24-hour clock by default.
+pub fn default_24h(locale: &Locale) -> bool {
+    let test_time = DateTime {
+        date: Date::try_new_gregorian(2024, 1, 1).unwrap(),
+        time: Time::try_new(13, 0, 0, 0).unwrap(),
+    };
+
+    let prefs = DateTimeFormatterPreferences::from(locale);
+    let Ok(dtf) = DateTimeFormatter::try_new(prefs, fieldsets::T::medium()) else {
+        return false;
+    };
+
+    let formatted = dtf.format(&test_time).to_string();
+
+    // If we see "13" in the output, it's 24-hour format
+    // If we see "1" (but not "13"), it's 12-hour format
+    formatted.contains("13")
+}
+
+/// The first day of the week of the locale, where Monday is `0` and Sunday is `6`.
+pub fn default_first_day(locale: &Locale) -> usize {
+    let Ok(week_info) = week::WeekInformation::try_new(week::WeekPreferences::from(locale)) else {
+        return 6;
+    };
+
+    match week_info.first_weekday {
+        Weekday::Monday => 0,
+        Weekday::Tuesday => 1,
+        Weekday::Wednesday => 2,
+        Weekday::Thursday => 3,
+        Weekday::Friday => 4,
+        Weekday::Saturday => 5,
+        Weekday::Sunday => 6,
+    }
+}
+
+/// The locales which are installed on the system, such as `de_DE.UTF-8`.
+pub async fn list_installed_locales() -> Vec<String> {
+    // Use 'locale -a' instead of 'localectl list-locales' for OpenRC compatibility
+    let output_result = tokio::process::Command::new("locale")
+        .arg("-a")
+        .output()
+        .await;
+
+    match output_result {
+        Ok(output) => {
+            let output_str = String::from_utf8(output.stdout).unwrap_or_default();
+            parse_locale_output(&output_str)
+        }
+        Err(why) => {
+            tracing::error!(?why, "failed to list available locales using 'locale -a'");
+            Vec::new()
+        }
+    }
+}
+
+/// The UTF-8 locales listed by `locale -a`, without the `C` and `POSIX` pseudo-locales.
+fn parse_locale_output(output: &str) -> Vec<String> {
+    // Regex to match pseudo-locales: C or POSIX, optionally followed by .anything
+    let pseudo_locale_re = Regex::new(r"^(C|POSIX)(\.|$)").unwrap();
+
+    // Regex to match UTF-8 encoded locales (case-insensitive)
+    // Supports optional modifiers after encoding (e.g., @euro, @valencia)
+    let utf8_encoding_re = Regex::new(r"(?i)\.(utf-?8)(@.*)?$").unwrap();
+
+    output
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !pseudo_locale_re.is_match(line))
+        .filter(|line| utf8_encoding_re.is_match(line))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Names POSIX locales by their region and language, such as `Germany (German)`.
+#[cfg(feature = "page-date")]
+pub struct RegionNames {
+    regions: RegionDisplayNames,
+    languages: LanguageDisplayNames,
+}
+
+#[cfg(feature = "page-date")]
+impl RegionNames {
+    /// Names locales in the language of `display`.
+    pub fn try_new(display: &Locale) -> Option<Self> {
+        Some(Self {
+            regions: RegionDisplayNames::try_new(display.into(), DisplayNamesOptions::default())
+                .ok()?,
+            languages: LanguageDisplayNames::try_new(
+                display.into(),
+                DisplayNamesOptions::default(),
+            )
+            .ok()?,
+        })
+    }
+
+    /// The name of a POSIX locale such as `de_DE.UTF-8`.
+    pub fn of(&self, lang_code: &str) -> Option<String> {
+        let locale = parse_locale(lang_code)?;
+        let language = self.languages.of(locale.id.language)?;
+        let region = self.regions.of(locale.id.region?)?;
+
+        Some(format!("{region} ({language})"))
+    }
+}
+
+/// The installed locales with their names in the language of `display`, sorted by name.
+#[cfg(feature = "page-date")]
+pub async fn installed_regions(display: Locale) -> Vec<(String, String)> {
+    let Some(names) = RegionNames::try_new(&display) else {
+        return Vec::new();
+    };
+
+    let mut regions: Vec<(String, String)> = list_installed_locales()
+        .await
+        .into_iter()
+        .filter_map(|lang_code| {
+            let name = names.of(&lang_code)?;
+            Some((lang_code, name))
+        })
+        .collect();
+
+    regions.sort_by(|(_, a), (_, b)| a.cmp(b));
+    regions
+}
+
+#[cfg(all(test, feature = "page-region"))]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct RecordingConfig(RefCell<Vec<(String, String)>>);
+
+    impl ConfigSet for RecordingConfig {
+        fn set<T: serde::Serialize>(
+            &self,
+            key: &str,
+            value: T,
+        ) -> Result<(), cosmic_config::Error> {
+            self.0
+                .borrow_mut()
+                .push((key.to_owned(), ron::to_string(&value).unwrap()));
+            Ok(())
+        }
+    }
+
+    fn written(region: &str) -> Vec<(String, String)> {
+        written_with(region, TimeFormatOverride::FollowLocale)
+    }
+
+    fn written_with(region: &str, time_format: TimeFormatOverride) -> Vec<(String, String)> {
+        let config = RecordingConfig::default();
+        write_region_defaults(&config, region, time_format);
+        config.0.into_inner()
+    }
+
+    #[test]
+    fn test_write_region_defaults_writes_applet_keys() {
+        let keys: Vec<_> = written("en_US.UTF-8").into_iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, [MILITARY_TIME, FIRST_DAY_OF_WEEK]);
+    }
+
+    #[test]
+    fn test_write_region_defaults_uses_region_conventions() {
+        assert_eq!(
+            written("en_US.UTF-8"),
+            [
+                (MILITARY_TIME.to_owned(), "false".to_owned()),
+                (FIRST_DAY_OF_WEEK.to_owned(), "6".to_owned()),
+            ]
+        );
+
+        assert_eq!(
+            written("de_DE.UTF-8"),
+            [
+                (MILITARY_TIME.to_owned(), "true".to_owned()),
+                (FIRST_DAY_OF_WEEK.to_owned(), "0".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_region_defaults_keeps_forced_clock() {
+        assert_eq!(
+            written_with("de_DE.UTF-8", TimeFormatOverride::Force12h),
+            [
+                (MILITARY_TIME.to_owned(), "false".to_owned()),
+                (FIRST_DAY_OF_WEEK.to_owned(), "0".to_owned()),
+            ]
+        );
+
+        assert_eq!(
+            written_with("en_US.UTF-8", TimeFormatOverride::Force24h),
+            [
+                (MILITARY_TIME.to_owned(), "true".to_owned()),
+                (FIRST_DAY_OF_WEEK.to_owned(), "6".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_region_defaults_falls_back_for_invalid_locales() {
+        assert_eq!(
+            written("not a locale"),
+            [
+                (MILITARY_TIME.to_owned(), "false".to_owned()),
+                (FIRST_DAY_OF_WEEK.to_owned(), "6".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_locale_output_handles_empty_input() {
+        let output = "";
+        let result = parse_locale_output(output);
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn test_parse_locale_output_preserves_locale_strings() {
+        let output = "en_US.utf8\nde_DE.utf8\nfr_FR.utf8\n";
+        let result = parse_locale_output(output);
+        assert_eq!(result.len(), 3);
+        assert!(result.contains(&"en_US.utf8".to_string()));
+    }
+
+    #[test]
+    fn test_parse_locale_output_filters_pseudo_locales() {
+        let output = "C\nC.utf8\nC.UTF-8\nPOSIX\nen_US.utf8\nde_DE.UTF-8\n";
+        let result = parse_locale_output(output);
+
+        // Should filter out all C and POSIX variants
+        assert!(!result.contains(&"C".to_string()));
+        assert!(!result.contains(&"C.utf8".to_string()));
+        assert!(!result.contains(&"C.UTF-8".to_string()));
+        assert!(!result.contains(&"POSIX".to_string()));
+
+        // Should keep actual locales
+        assert!(result.contains(&"en_US.utf8".to_string()));
+        assert!(result.contains(&"de_DE.UTF-8".to_string()));
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_locale_output_accepts_only_utf8_locales() {
+        let output =
+            "en_US\nen_US.utf8\nen_US.UTF-8\nar_IN\nar_IN.utf8\nde_DE.iso88591\nfr_FR.UTF-8\n";
+        let result = parse_locale_output(output);
+
+        // Should accept UTF-8 variants
+        assert!(result.contains(&"en_US.utf8".to_string()));
+        assert!(result.contains(&"en_US.UTF-8".to_string()));
+        assert!(result.contains(&"ar_IN.utf8".to_string()));
+        assert!(result.contains(&"fr_FR.UTF-8".to_string()));
+
+        // Should filter out non-UTF-8 encoded locales
+        assert!(!result.contains(&"en_US".to_string()));
+        assert!(!result.contains(&"ar_IN".to_string()));
+        assert!(!result.contains(&"de_DE.iso88591".to_string()));
+
+        assert_eq!(result.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_locale_output_filters_any_c_posix_variant() {
+        let output = "C\nC.iso88591\nC.anything\nPOSIX\nPOSIX.utf8\nen_US.utf8\n";
+        let result = parse_locale_output(output);
+
+        // Should filter out any C or POSIX variant regardless of encoding
+        assert!(!result.contains(&"C".to_string()));
+        assert!(!result.contains(&"C.iso88591".to_string()));
+        assert!(!result.contains(&"C.anything".to_string()));
+        assert!(!result.contains(&"POSIX".to_string()));
+        assert!(!result.contains(&"POSIX.utf8".to_string()));
+
+        // Should keep actual locales
+        assert!(result.contains(&"en_US.utf8".to_string()));
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_locale_output_handles_whitespace() {
+        let output = "  en_US.utf8  \n\t de_DE.UTF-8\t\n   fr_FR.utf8   \n";
+        let result = parse_locale_output(output);
+
+        // Should handle leading/trailing whitespace
+        assert!(result.contains(&"en_US.utf8".to_string()));
+        assert!(result.contains(&"de_DE.UTF-8".to_string()));
+        assert!(result.contains(&"fr_FR.utf8".to_string()));
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_locale_output_handles_empty_lines() {
+        let output = "en_US.utf8\n\n\nde_DE.UTF-8\n\n";
+        let result = parse_locale_output(output);
+
+        // Should skip empty lines
+        assert!(result.contains(&"en_US.utf8".to_string()));
+        assert!(result.contains(&"de_DE.UTF-8".to_string()));
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_locale_output_catalan_not_filtered_as_pseudo() {
+        let output = "C\nca_ES.UTF-8\nca_ES.utf8\ncs_CZ.UTF-8\nen_US.utf8\n";
+        let result = parse_locale_output(output);
+
+        // Should filter out C but not Catalan (ca_*) or Czech (cs_*)
+        assert!(!result.contains(&"C".to_string()));
+        assert!(result.contains(&"ca_ES.UTF-8".to_string()));
+        assert!(result.contains(&"ca_ES.utf8".to_string()));
+        assert!(result.contains(&"cs_CZ.UTF-8".to_string()));
+        assert_eq!(result.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_locale_output_handles_locale_modifiers() {
+        let output = "en_US.UTF-8@euro\nca_ES.UTF-8@valencia\nde_DE.utf8\n";
+        let result = parse_locale_output(output);
+
+        // Locales with modifiers should be accepted
+        assert!(result.contains(&"en_US.UTF-8@euro".to_string()));
+        assert!(result.contains(&"ca_ES.UTF-8@valencia".to_string()));
+        assert!(result.contains(&"de_DE.utf8".to_string()));
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_locale_output_case_variations() {
+        let output = "en_US.UTF-8\nen_US.utf-8\nen_US.utf8\nen_US.UTF8\nde_DE.Utf8\n";
+        let result = parse_locale_output(output);
+
+        // All case variations should be accepted (case-insensitive regex)
+        assert!(result.contains(&"en_US.UTF-8".to_string()));
+        assert!(result.contains(&"en_US.utf-8".to_string()));
+        assert!(result.contains(&"en_US.utf8".to_string()));
+        assert!(result.contains(&"en_US.UTF8".to_string()));
+        assert!(result.contains(&"de_DE.Utf8".to_string()));
+        assert_eq!(result.len(), 5);
+    }
+}
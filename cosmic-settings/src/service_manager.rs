@@ -27,6 +27,34 @@ pub trait ServiceManager {
     /// Enable the service to start on boot and start it now.
     fn enable(&self) -> Pin<Box<dyn Future<Output = ()> + Send>>;
 
+    /// The privileged command which restarts the service if it is running, for callers
+    /// which need to run it in the same `pkexec` invocation as other commands.
+    fn restart_command(&self) -> Vec<String>;
+
+    /// Restart the service if it is running, so that it reloads its configuration.
+    fn restart(&self) -> Pin<Box<dyn Future<Output = std::io::Result<()>> + Send>> {
+        let command = self.restart_command();
+        Box::pin(async move {
+            if command.is_empty() {
+                return Ok(());
+            }
+
+            let status = tokio::process::Command::new("pkexec")
+                .args(&command)
+                .status()
+                .await?;
+
+            if !status.success() {
+                return Err(std::io::Error::other(format!(
+                    "{} exited with {status}",
+                    command.join(" ")
+                )));
+            }
+
+            Ok(())
+        })
+    }
+
     /// Whether the service is installed on this system.
     fn is_installed(&self) -> bool;
 }
@@ -130,6 +158,10 @@ impl ServiceManager for MockServiceManager {
         Box::pin(async {})
     }
 
+    fn restart_command(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     fn is_installed(&self) -> bool {
         self.installed
     }
@@ -192,6 +224,14 @@ impl ServiceManager for SystemDServiceManager {
         })
     }
 
+    fn restart_command(&self) -> Vec<String> {
+        vec![
+            "systemctl".to_owned(),
+            "try-restart".to_owned(),
+            self.service_name.clone(),
+        ]
+    }
+
     fn is_installed(&self) -> bool {
         std::process::Command::new("systemctl")
             .args(["cat", &self.service_name])
@@ -283,6 +323,15 @@ impl ServiceManager for OpenRcServiceManager {
         })
     }
 
+    fn restart_command(&self) -> Vec<String> {
+        vec![
+            "rc-service".to_owned(),
+            "--ifstarted".to_owned(),
+            self.service_name.clone(),
+            "restart".to_owned(),
+        ]
+    }
+
     fn is_installed(&self) -> bool {
         std::path::Path::new("/etc/init.d")
             .join(&self.service_name)
@@ -375,6 +424,10 @@ impl ServiceManager for NoOpServiceManager {
         Box::pin(async {})
     }
 
+    fn restart_command(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     fn is_installed(&self) -> bool {
         false
     }
@@ -409,6 +462,10 @@ mod tests {
                 Box::pin(async {})
             }
 
+            fn restart_command(&self) -> Vec<String> {
+                Vec::new()
+            }
+
             fn is_installed(&self) -> bool {
                 self.installed
             }
@@ -452,6 +509,10 @@ mod tests {
                 Box::pin(async {})
             }
 
+            fn restart_command(&self) -> Vec<String> {
+                Vec::new()
+            }
+
             fn is_installed(&self) -> bool {
                 self.installed
             }
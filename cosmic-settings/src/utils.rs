@@ -37,6 +37,23 @@ pub fn forward_event_loop<M: 'static + Send, T: Future<Output = ()> + Send + 'st
     (cancel_tx, task)
 }
 
+/// Formats a size in bytes with a binary unit, such as `1.50 MiB`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+    const FACTOR: f64 = 1024.0;
+
+    if bytes == 0 {
+        return String::from("0 B");
+    }
+
+    let bytes_f64 = bytes as f64;
+    let exp = (bytes_f64.ln() / FACTOR.ln()).floor() as usize;
+    let exp = exp.min(UNITS.len() - 1);
+    let value = bytes_f64 / FACTOR.powi(exp as i32);
+
+    format!("{:.2} {}", value, UNITS[exp])
+}
+
 /// On process failure, return stderr as `String`.
 pub fn map_stderr_output(result: io::Result<process::Output>) -> Result<(), String> {
     result.map_err(|why| why.to_string()).and_then(|output| {
@@ -59,3 +76,15 @@ macro_rules! slab {
         )+
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(512), "512.00 B");
+        assert_eq!(format_size(1_572_864), "1.50 MiB");
+    }
+}
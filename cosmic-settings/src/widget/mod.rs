@@ -220,16 +220,30 @@ pub fn go_next_with_item<'a, Msg: 'static>(
     .on_press_maybe(msg_opt.into())
 }
 
+/// A notice with an icon, a message, and its actions.
+pub fn notice_item<'a, Msg: 'static>(
+    icon_name: &'static str,
+    text: String,
+    actions: Vec<Element<'a, Msg>>,
+) -> Element<'a, Msg> {
+    settings::item::builder(text)
+        .icon(icon::from_name(icon_name).size(16))
+        .control(
+            row::with_children(actions)
+                .spacing(theme::spacing().space_xxs)
+                .align_y(Alignment::Center),
+        )
+        .apply(container)
+        .class(theme::Container::List)
+        .into()
+}
+
 pub fn selection_context_item<'a, Msg: 'static>(
     name: &'a str,
     selected: bool,
     msg_opt: impl Into<Option<Msg>>,
 ) -> list::ListButton<'a, Msg> {
-    let svg_accent = Rc::new(|theme: &cosmic::Theme| widget::svg::Style {
-        color: Some(theme.cosmic().accent_text_color().into()),
-    });
-
-    settings::item_row(vec![
+    selection_context_item_with(
         text::body(name)
             .class(if selected {
                 theme::Text::Accent
@@ -237,8 +251,24 @@ pub fn selection_context_item<'a, Msg: 'static>(
                 theme::Text::Default
             })
             .wrapping(Wrapping::Word)
-            .width(Length::Fill)
-            .into(),
+            .width(Length::Fill),
+        selected,
+        msg_opt,
+    )
+}
+
+/// A selection context item with a custom label, such as one with highlighted text.
+pub fn selection_context_item_with<'a, Msg: 'static>(
+    label: impl Into<Element<'a, Msg>>,
+    selected: bool,
+    msg_opt: impl Into<Option<Msg>>,
+) -> list::ListButton<'a, Msg> {
+    let svg_accent = Rc::new(|theme: &cosmic::Theme| widget::svg::Style {
+        color: Some(theme.cosmic().accent_text_color().into()),
+    });
+
+    settings::item_row(vec![
+        label.into(),
         if selected {
             icon::from_name("object-select-symbolic")
                 .size(16)
@@ -941,12 +941,50 @@ time = Time & language
 time-date = Date & time
     .auto = Set automatically
     .auto-ntp = Date & time will update automatically when the time zone is set
+    .ntp-server = Time server
+    .ntp-server-empty = Enter a time server
+    .ntp-server-invalid = This time server could not be found
+    .ntp-status = Time synchronization
+    .ntp-synchronized = NTP synchronized
+    .ntp-synchronized-accuracy = NTP synchronized (±{ $accuracy }ms)
+    .ntp-waiting = NTP enabled, waiting for sync
+    .hardware-clock = Hardware clock uses
+    .hardware-clock-utc = UTC
+    .hardware-clock-local = Local time
+    .hardware-clock-warning = A hardware clock in local time can drift when clocks change for daylight saving time. UTC is recommended unless another operating system on this computer requires local time.
+    .hardware-clock-switch = Switch to UTC
+    .secondary-locale = Show time in another language
+    .secondary-locale-none = None
 
 time-zone = Time zone
     .auto = Automatic time zone
     .auto-info = Requires location services and internet access
+    .switch-language = Switch
+    .next-dst = Clocks change on { $date }
+    .dst-reminder = Add reminder
+    .dst-reminder-forward = Clocks change: set your clock forward
+    .dst-reminder-back = Clocks change: set your clock back
+    .convert = Convert a time to UTC
+    .convert-invalid = Enter a time such as 09:00
+    .ical-import = Import from calendar file
+    .ical-found = The calendar file uses { $timezone }.
+    .ical-use = Use time zone
+    .ical-choose = Choose file
+    .ical-missing = The calendar file does not define a time zone.
+    .ical-unknown = { $timezone } is not supported. Only IANA-registered time zones can be used.
+    .source-tzdata = Time zones provided by { $tzdata }
+    .source-systemd = Time zones provided by systemd-timedated
+    .source-both = Time zones listed by systemd-timedated from { $tzdata }
+    .clock-drift = Without NTP synchronization, the clock will slowly drift from the correct time.
+    .country-count = { $count } time zones in this country
+    .country-other = Other time zones in this country
+    .country-select = Select
+
+timezone-language-suggestion = Your new time zone suggests { $language }. Would you like to switch to { $language }?
 
 time-format = Date & time format
+    .follow-locale = Use the region's time format
+    .twelve = 12-hour time
     .twenty-four = 24-hour time
     .show-seconds = Show seconds
     .first = First day of week
@@ -958,22 +996,165 @@ time-format = Date & time format
 
 time-region = Region & language
 
+calendar-system = Calendar system
+    .buddhist = Buddhist
+    .chinese = Chinese
+    .coptic = Coptic
+    .dangi = Dangi
+    .ethiopic = Ethiopic
+    .gregorian = Gregorian
+    .hebrew = Hebrew
+    .indian = Indian national
+    .islamic-civil = Hijri (tabular, civil epoch)
+    .islamic-tbla = Hijri (tabular, astronomical epoch)
+    .islamic-umalqura = Hijri (Umm al-Qura)
+    .japanese = Japanese
+    .persian = Persian
+    .roc = Minguo
+
 formatting = Formatting
     .dates = Dates
+    .day-month-year = Day / Month / Year
+    .month-day-year = Month / Day / Year
+    .year-month-day = Year / Month / Day
+    .short-date = Short date
+    .full-date = Full date
     .time = Time
+    .am-pm = AM/PM
+    .time-with-timezone = Time with time zone
+    .timezone = Timezone name
     .date-and-time = Date & time
+    .weekdays = Weekdays
+    .weekend = Weekend
+    .months = Months
+    .era = Era
+    .calendar-system = Calendar system
+    .duration = Duration
+    .percent = Percent
+    .scientific = Scientific
+    .compact-number = Compact number
+    .emoji = Emoji rendering
+    .ordinals = Ordinals
+    .list-separator = List separator
+    .sort-order = Sort order
     .numbers = Numbers
+    .number-separators = Decimal: { $decimal } Thousands: { $thousands }
+    .separators = Separators
+    .numeral-system = Numeral system
+    .separators-summary = Decimal { $decimal }  Thousands { $thousands }  List { $list }  Signs { $plus } { $minus }
+    .negative-number = Negative number
+    .currency = Currency
+    .address = Address
+    .name = Name
+    .bidi-sample = Bidirectional text
     .measurement = Measurement
     .paper = Paper
+    .preview-size = Preview text size
+    .live-preview = Preview the current date and time
+    .copy-docker = Copy for Docker
+    .date-skeleton = Custom date skeleton (ICU)
+    .date-skeleton-docs = Skeleton symbols
 
 preferred-languages = Preferred languages
     .desc = The order of languages determines which language is used for the user interface. Changes take effect on next login.
+    .make-primary = Make primary language
+
+language-order = How language order works
+translation-lookup = How translations are resolved
+    .in-use = In use
+    .overridden = Overridden by { $variable }
+language-order-example = Applications will display in { $first }.
+    .fallback = Applications will display in { $first }. If a translation is unavailable, they fall back to { $second }.
+    .fallbacks = Applications will display in { $first }. If a translation is unavailable, they fall back to { $second }, then { $third }.
 
 add-language = Add language
     .context = Add Language
 install-additional-languages = Install additional languages
+export-ansible-task = Export Ansible task
+clear-search-history = Clear history
+language-search-results = Showing { $visible } of { $total } languages
+    .none = No languages matching '{ $query }'
+continent = Continent
+    .africa = Africa
+    .americas = Americas
+    .asia = Asia
+    .europe = Europe
+    .oceania = Oceania
+script = Script
+    .latin = Latin
+    .cyrillic = Cyrillic
+    .arabic = Arabic
+    .cjk = Chinese, Japanese and Korean
+    .devanagari = Devanagari
+    .greek = Greek
+    .hebrew = Hebrew
+    .thai = Thai
+    .other = Other
+writing-direction = Writing direction
+    .ltr = LTR
+    .rtl = RTL
+    .ttb = TTB
+deprecated-language-code = Outdated language code, now { $canonical }
+    .fix = Fix
+installed-languages = Installed
+available-languages = Available
 region = Region
 
+language-not-installed = Not installed
+download-language-pack = Download
+language-pack = Language pack
+    .pending = Waiting…
+    .size = Language pack: { $size }
+    .total-size = Language packs use { $size } of disk space
+    .remove = Remove (its { $size } language pack stays installed)
+language-suggestion = Your keyboard layout suggests { $language }. Add it to your preferred languages?
+    .location = Your location suggests { $language }. Add it to your preferred languages?
+lc-all-override = LC_ALL is set to { $locale } and overrides all other locale settings. Remove it to allow per-category customization.
+    .remove = Remove LC_ALL
+    .dialog-title = Remove LC_ALL?
+    .dialog-description = The system will use the language and formats configured for each category again. This affects all users.
+shell-locale-overrides = { $count ->
+        [one] A shell startup file sets a locale variable, which overrides these settings in terminals.
+        *[other] Shell startup files set { $count } locale variables, which override these settings in terminals.
+    }
+    .open = Open in text editor
+app-locale-support = { $supported } of { $total } translation catalogs cover { $locale }
+    .details = Show catalogs
+    .context = Translation catalogs
+locale-diverged = The system language ({ $system }) differs from the language of your account ({ $user }).
+    .use-system = Use system locale
+    .use-user = Use user locale
+sort-order-changed = Your language change will affect how files and folders are sorted in the file manager. Some file manager apps may need to be restarted.
+locale-relogin-required = Log out and back in to apply the language change to all running applications.
+keyboard-layout-mismatch = Your keyboard layout is meant for a different language. Consider changing it in the keyboard settings.
+input-method-hint = Typing { $language } requires IBus with the { $engine } input method.
+    .install = Install
+    .learn-more = Learn more
+missing-fonts = Some text may not display correctly until these fonts are installed: { $fonts }
+    .install = Install fonts
+locale-not-persisted = Your locale changes will not persist after reboot. Click "Apply system-wide" to save them permanently.
+apply-system-wide = Apply system-wide
+read-locale-conf = Read from /etc/locale.conf
+locale-files-conflict = These locale config files disagree with each other:
+consolidate = Consolidate
+restore-defaults = Restore defaults
+    .title = Restore the default language?
+    .description = Every preferred language except { $language } will be removed, and the system-wide language and formats will be reset to { $language }. This affects all users. The following languages will be removed:
+apply-to-login-screen = Apply to login screen
+import-user-locale = Copy from user…
+    .desc = Lists every user account on this computer, including those that are not logged in.
+    .apply = Copy languages
+    .dialog-title = Copy the languages of { $user }?
+    .dialog-description = Your preferred languages and the system language will be replaced with { $language }.
+auto-select-region = Use the region of added languages
+locale-change-history = Change history
+    .restore = Restore
+locale-file-dialog = Apply the locale from { $path }?
+    .description = The following variables will be applied system-wide:
+language-counts = { $configured } configured / { $available } available
+locale-completeness = { $percent }% of formats set
+icu-unsupported-locale = ICU data is not available for { $locale }. Formatting previews may be inaccurate.
+
 ## Applications
 
 applications = Applications
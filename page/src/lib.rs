@@ -107,6 +107,12 @@ pub trait Page<Message: Clone + 'static>: Downcast {
     fn title(&self) -> Option<&str> {
         None
     }
+
+    /// A count to display beside the page's entry in page lists.
+    #[inline]
+    fn badge(&self) -> Option<u32> {
+        None
+    }
 }
 
 impl_downcast!(Page<Message> where Message: Clone);